@@ -7,10 +7,25 @@ use graphics::{Entity, Transforms};
 use world::{World, WorldEvent, TickEvent, TickAfterEvent};
 use math::{Vec2, Vec3};
 use being_args::{BeingArgs};
+use error::{PolyclopsError};
 
 pub trait BeingType<T: BeingType<T>>: Send + Sync + Clone + Eq + PartialEq + Hash {
-    fn make_being(Arc<RwLock<IDManager>>, T, Arc<RwLock<World<T>>>, BeingArgs) -> Vec<WorldEvent<T>>;
-    fn make_base(Arc<RwLock<IDManager>>, T, Arc<RwLock<World<T>>>) -> Vec<WorldEvent<T>>;
+    type Event: Send + Sync + Clone;
+    // returns the id of the being it added to the world along with that being's starting events
+    fn make_being(Arc<IDManager>, T, Arc<RwLock<World<T>>>, BeingArgs) -> (ID, Vec<WorldEvent<T>>);
+    fn make_base(Arc<IDManager>, T, Arc<RwLock<World<T>>>) -> Vec<WorldEvent<T>>;
+    #[allow(unused_variables)]
+    fn handle_custom_event(manager: Arc<IDManager>, event: Self::Event, world: Arc<RwLock<World<T>>>) -> Vec<WorldEvent<T>> {
+        vec!()
+    }
+    #[allow(unused_variables)]
+    fn get_custom_event_rank(event: &Self::Event) -> u32 {
+        1
+    }
+    // interpolates pos, sca and rot between ticks when drawing beings with automatic models
+    fn is_interpolated(&self) -> bool {
+        true
+    }
 }
 
 pub trait Being<T: BeingType<T>>: Send + Sync {
@@ -19,9 +34,45 @@ pub trait Being<T: BeingType<T>>: Send + Sync {
     fn get_entity(&self, id: u32) -> Option<&Arc<RwLock<Entity>>> {
         self.get_entities().get(&id)
     }
+    fn find_entity(&self, id: u32) -> Result<&Arc<RwLock<Entity>>, PolyclopsError> {
+        self.get_entity(id).ok_or(PolyclopsError::MissingEntity(id))
+    }
     fn get_entities(&self) -> &HashMap<u32, Arc<RwLock<Entity>>>;
     fn tick(&self, &World<T>, &Transforms, &f32) -> Vec<TickEvent<T>>;
     fn tick_after(&self, &World<T>, &Transforms) -> Vec<TickAfterEvent<T>>;
+    // called on every being and base after a resize, once the world and its camera have the new aspect ratio,
+    // for rebuilding projections a being sets itself like a hud layer's
+    #[allow(unused_variables)]
+    fn resize(&self, world: &World<T>, resolution: Vec2, aspect_ratio: f32) -> Vec<TickEvent<T>> {
+        vec!()
+    }
+    fn ticks_while_paused(&self) -> bool {
+        false
+    }
+    fn is_integrated(&self) -> bool {
+        true
+    }
+    fn get_damping(&self) -> f32 {
+        0.0
+    }
+    // true when tick and tick after do nothing unless an event or message reaches this being,
+    // it then sleeps once it stops moving and is skipped until something targets it
+    fn can_sleep(&self) -> bool {
+        false
+    }
+    // the game writes every entity's model matrix from pos, sca and rot whenever they change,
+    // return false to push TransformEvent::Model yourself
+    fn has_automatic_model(&self) -> bool {
+        true
+    }
+    // the local point the being scales and rotates around and that ends up at its pos
+    fn get_pivot(&self) -> Vec3 {
+        Vec3::zero()
+    }
+    // name, tags, texture and properties to save with this being, the transform fields are read from the being itself
+    fn get_being_args(&self) -> BeingArgs {
+        BeingArgs::new()
+    }
     fn get_sca2(&self) -> Vec2 {
         Vec2::from(self.get_sca3())
     }
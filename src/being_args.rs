@@ -1,3 +1,5 @@
+use std::collections::{HashMap};
+
 use math::{Vec3};
 
 #[derive(Clone)]
@@ -7,6 +9,59 @@ pub struct BeingArgs {
     pub acc: Option<Box<Vec3>>,
     pub sca: Option<Box<Vec3>>,
     pub rot: Option<Box<Vec3>>,
+    pub name: Option<String>,
+    pub tags: Vec<String>,
+    // the name of a texture the being type knows how to load, used instead of its base's texture
+    pub texture: Option<String>,
+    pub properties: HashMap<String, Property>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum Property {
+    Bool(bool),
+    Int(i32),
+    Float(f32),
+    Text(String),
+    Vec3(Vec3),
+}
+
+impl Property {
+    pub fn as_bool(&self) -> Option<bool> {
+        match *self {
+            Property::Bool(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_int(&self) -> Option<i32> {
+        match *self {
+            Property::Int(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    // ints are widened so whole numbers written without a decimal point still read as floats
+    pub fn as_float(&self) -> Option<f32> {
+        match *self {
+            Property::Float(value) => Some(value),
+            Property::Int(value) => Some(value as f32),
+            _ => None,
+        }
+    }
+
+    pub fn as_text(&self) -> Option<&str> {
+        match *self {
+            Property::Text(ref value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_vec3(&self) -> Option<Vec3> {
+        match *self {
+            Property::Vec3(value) => Some(value),
+            _ => None,
+        }
+    }
 }
 
 impl BeingArgs {
@@ -17,6 +72,10 @@ impl BeingArgs {
             acc: None,
             sca: None,
             rot: None,
+            name: None,
+            tags: vec!(),
+            texture: None,
+            properties: HashMap::new(),
         }
     }
 
@@ -44,4 +103,32 @@ impl BeingArgs {
         self.rot = Some(Box::new(vec3));
         self
     }
+
+    pub fn with_name(mut self, name: &str) -> BeingArgs {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    pub fn with_tag(mut self, tag: &str) -> BeingArgs {
+        self.tags.push(tag.to_string());
+        self
+    }
+
+    pub fn with_texture(mut self, texture: &str) -> BeingArgs {
+        self.texture = Some(texture.to_string());
+        self
+    }
+
+    pub fn with_property(mut self, key: &str, property: Property) -> BeingArgs {
+        self.properties.insert(key.to_string(), property);
+        self
+    }
+
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|other| other == tag)
+    }
+
+    pub fn get_property(&self, key: &str) -> Option<&Property> {
+        self.properties.get(key)
+    }
 }
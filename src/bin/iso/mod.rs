@@ -1,8 +1,8 @@
+use std::borrow::{Cow};
 use std::sync::{Arc, RwLock};
-use std::f32::consts::{PI};
-use polyclops::{init, Window, WindowArgs, Game, World, BeingType, Being, IDManager, WorldEvent,
+use polyclops::{init, Renderer, Window, WindowArgs, Game, GameConfig, World, BeingType, Being, ID, IDManager, WorldEvent,
     TickEvent, TickAfterEvent, TransformEvent, EntityGraphicsEvent, EntityIDEvent, EntityIDType,
-    DrawMethod, DepthTestMethod, CullingMethod, Mat4, Vec3, Vec2, Vertex, BeingArgs,
+    DrawMethod, DepthTestMethod, CullingMethod, Mat4, Vec2, Vertex, BeingArgs, NamedBeingType, scene_from_json,
 };
 
 mod tile;
@@ -12,26 +12,28 @@ use self::tile::Tile;
 const TILE_TEXTURE: &'static [u8] = include_bytes!("../../../assets/texture.png");
 const TILE_BRICK_TEXTURE: &'static [u8] = include_bytes!("../../../assets/TileBrick.png");
 const TILE_WOOD_TEXTURE: &'static [u8] = include_bytes!("../../../assets/TileWood.png");
+fn get_texture(name: &str) -> Option<&'static [u8]> {
+    match name {
+        "Texture" => Some(TILE_TEXTURE),
+        "TileBrick" => Some(TILE_BRICK_TEXTURE),
+        "TileWood" => Some(TILE_WOOD_TEXTURE),
+        _ => None,
+    }
+}
+
+const SCENE: &'static str = include_str!("../../../assets/iso.json");
 
 pub fn main() {
     let manager = init();
 
-    let mut window = Window::new(WindowArgs::Borderless("iso".to_string()));
+    let mut window = Window::new(WindowArgs::Borderless("iso".to_string())).expect("Unable to Create Window");
 
     let resolution = window.get_resolution_vec2();
 
     let thread_count = 8;
 
-    let mut game: Game<IBT> = Game::<IBT>::new(manager, thread_count, World::new(resolution), resolution);
-    let mut events = vec!(WorldEvent::Tick(TickEvent::NewBase(IBT::Tile)));
-    for _ in 0..1 {
-        events.push(WorldEvent::Tick(TickEvent::NewBeing(
-            IBT::Tile, BeingArgs::new()
-            .with_pos(Vec3::from([0.0, 0.0, 0.0]))
-            .with_sca(Vec3::from([0.1, 0.1, 1.0]))
-            .with_rot(Vec3::from([-45.0 * PI / 180.0, 0.0, 45.0 * PI / 180.0]))
-        )));
-    }
+    let mut game: Game<IBT> = Game::<IBT>::new(manager, thread_count, World::new(resolution), resolution, GameConfig::new());
+    let events = scene_from_json::<IBT>(SCENE).expect("Unable to Load Scene");
     game.run(events, &mut window);
 }
 
@@ -44,36 +46,66 @@ pub mod iso_being_type {
     }
 }
 
+impl NamedBeingType for IBT {
+    fn get_name(&self) -> &'static str {
+        match *self {
+            IBT::Tile => "Tile",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<IBT> {
+        match name {
+            "Tile" => Some(IBT::Tile),
+            _ => None,
+        }
+    }
+}
+
 impl BeingType<IBT> for IBT {
-    fn make_being(manager: Arc<RwLock<IDManager>>, being_type: IBT, world: Arc<RwLock<World<IBT>>>, being_args: BeingArgs) -> Vec<WorldEvent<IBT>> {
+    type Event = ();
+
+    fn make_being(manager: Arc<IDManager>, being_type: IBT, world: Arc<RwLock<World<IBT>>>, being_args: BeingArgs) -> (ID, Vec<WorldEvent<IBT>>) {
         let mut events: Vec<WorldEvent<IBT>> = vec!();
         let being = match being_type.clone() {
             IBT::Tile => {
+                // scene data can name any texture, an unknown one keeps the base's texture instead of stopping the game
+                let texture = being_args.texture.clone().and_then(|name| {
+                    let texture = get_texture(&name);
+                    if texture.is_none() {
+                        eprintln!("Unable to Find Texture {} in Make Being IBT, using the base texture", name);
+                    }
+                    texture
+                });
                 let being = {
                     let world = world.read().expect("Unable to Read World in Make Being IBT");
                     let base = world.get_base(being_type.clone()).expect("Unable to Get Base in Make Being IBT");
                     Tile::new_from_base(manager, base, being_args)
                 };
                 let id = being.get_id();
-                events.push(WorldEvent::Tick(TickEvent::EntityID(id, tile::ENTITY_TILE_ID, EntityIDEvent::UseNewID(vec!(EntityIDType::Model)))));
+                let mut new_ids = vec!(EntityIDType::Model);
+                match texture {
+                    Some(texture) => {
+                        new_ids.push(EntityIDType::Texture);
+                        events.push(WorldEvent::TickAfter(TickAfterEvent::Entity(id, tile::ENTITY_TILE_ID, EntityGraphicsEvent::Texture(Cow::Borrowed(texture)))));
+                    },
+                    None => (),
+                }
+                events.push(WorldEvent::Tick(TickEvent::EntityID(id, tile::ENTITY_TILE_ID, EntityIDEvent::UseNewID(new_ids))));
                 let mat4 = Mat4::identity();
                 events.push(WorldEvent::Tick(TickEvent::Transform(id, tile::ENTITY_TILE_ID, TransformEvent::Model(mat4, mat4.to_inverse()))));
                 being
             },
         };
+        let id = being.get_id();
         world.write().expect("Unable to Write World in Make Being IBT").add_being(Box::new(being));
-        events
+        (id, events)
     }
 
-    fn make_base(manager: Arc<RwLock<IDManager>>, being_type: IBT, world: Arc<RwLock<World<IBT>>>) -> Vec<WorldEvent<IBT>> {
+    fn make_base(manager: Arc<IDManager>, being_type: IBT, world: Arc<RwLock<World<IBT>>>) -> Vec<WorldEvent<IBT>> {
         let mut events: Vec<WorldEvent<IBT>> = vec!();
         let being = match being_type.clone() {
             IBT::Tile => {
                 let being = Tile::new_base(manager);
-                let mat4 = Mat4::orthographic(0.1, 100.0, 75.0, world.read().expect("Unable to Read World in Make Base IBT").get_aspect_ratio());
-                events.push(WorldEvent::Tick(TickEvent::TransformBase(being_type.clone(), tile::ENTITY_TILE_ID, TransformEvent::Perspective(mat4, mat4.to_inverse()))));
-                let mat4 = Mat4::view(0.0, 0.0, Vec3::zero());
-                events.push(WorldEvent::Tick(TickEvent::TransformBase(being_type.clone(), tile::ENTITY_TILE_ID, TransformEvent::View(mat4, mat4.to_inverse()))));
                 let mat4 = Mat4::identity();
                 events.push(WorldEvent::Tick(TickEvent::TransformBase(being_type.clone(), tile::ENTITY_TILE_ID, TransformEvent::Model(mat4, mat4.to_inverse()))));
                 events.push(WorldEvent::TickAfter(TickAfterEvent::EntityBase(being_type.clone(), tile::ENTITY_TILE_ID, EntityGraphicsEvent::Vertices(vec!(
@@ -86,7 +118,7 @@ impl BeingType<IBT> for IBT {
                     0, 1, 2,
                     2, 3, 0,
                 )))));
-                events.push(WorldEvent::TickAfter(TickAfterEvent::EntityBase(being_type.clone(), tile::ENTITY_TILE_ID, EntityGraphicsEvent::Texture(TILE_BRICK_TEXTURE))));
+                events.push(WorldEvent::TickAfter(TickAfterEvent::EntityBase(being_type.clone(), tile::ENTITY_TILE_ID, EntityGraphicsEvent::Texture(Cow::Borrowed(TILE_BRICK_TEXTURE)))));
                 events.push(WorldEvent::TickAfter(TickAfterEvent::EntityBase(being_type.clone(), tile::ENTITY_TILE_ID, EntityGraphicsEvent::DrawMethod(DrawMethod::Both(DepthTestMethod::IfLess, CullingMethod::Clockwise)))));
                 being
             },
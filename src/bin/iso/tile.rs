@@ -1,6 +1,6 @@
 use std::sync::{Arc, RwLock};
 use std::collections::{HashMap};
-use polyclops::{Being, ID, IDManager, IDType, Entity, TickEvent, TickAfterEvent, TransformEvent, World, Transforms, Vec3, Mat4, BeingArgs};
+use polyclops::{Being, ID, IDManager, IDType, Entity, TickEvent, TickAfterEvent, World, Transforms, Vec3, BeingArgs};
 
 use iso::iso_being_type::IsoBeingType as IBT;
 
@@ -14,10 +14,11 @@ pub struct Tile {
     acc: Vec3,
     sca: Vec3,
     rot: Vec3,
+    texture: Option<String>,
 }
 
 impl Tile {
-    pub fn new_base(manager: Arc<RwLock<IDManager>>) -> Tile {
+    pub fn new_base(manager: Arc<IDManager>) -> Tile {
         let mut entities = HashMap::new();
         let tile_entity = Entity::new(manager.clone());
         entities.insert(ENTITY_TILE_ID, Arc::new(RwLock::new(tile_entity)));
@@ -29,10 +30,11 @@ impl Tile {
             acc: Vec3::zero(),
             sca: Vec3::one(),
             rot: Vec3::zero(),
+            texture: None,
         }
     }
 
-    pub fn new_from_base(manager: Arc<RwLock<IDManager>>, base: &Arc<RwLock<Box<Being<IBT>>>>, being_args: BeingArgs) -> Tile {
+    pub fn new_from_base(manager: Arc<IDManager>, base: &Arc<RwLock<Box<Being<IBT>>>>, being_args: BeingArgs) -> Tile {
         let base = base.read().expect("Unable to Read Base in New From Base in Tile");
         let mut entities: HashMap<u32, Arc<RwLock<Entity>>> = HashMap::new();
         for entry in base.get_entities() {
@@ -66,6 +68,7 @@ impl Tile {
             acc: acc,
             sca: sca,
             rot: rot,
+            texture: being_args.texture,
         }
     }
 }
@@ -83,12 +86,18 @@ impl Being<IBT> for Tile {
         &self.entities
     }
 
+    fn can_sleep(&self) -> bool {
+        true
+    }
+
+    fn get_being_args(&self) -> BeingArgs {
+        let mut being_args = BeingArgs::new();
+        being_args.texture = self.texture.clone();
+        being_args
+    }
+
     fn tick(&self, world: &World<IBT>, transforms: &Transforms, delta_time: &f32) -> Vec<TickEvent<IBT>> {
         let mut events = vec!();
-        for entry in self.get_entities() {
-            let mat4 = Mat4::translation_from_vec3(self.get_pos3()) * Mat4::scalation_from_vec3(self.get_sca3()) * Mat4::rotation_from_vec3(self.get_rot3());
-            events.push(TickEvent::Transform(self.get_id(), *entry.0, TransformEvent::Model(mat4, mat4.to_inverse())))
-        }
         events
     }
 
@@ -0,0 +1,101 @@
+use std::f32::consts::{PI};
+
+use math::{Vec3, Mat4};
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Projection {
+    Orthographic,
+    Perspective,
+}
+
+// the world's default perspective and view, entities that use_camera(false) keep their own matrices instead
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Camera {
+    pub projection: Projection,
+    pub pos: Vec3,
+    // degrees, like Mat4::view
+    pub pitch: f32,
+    pub yaw: f32,
+    // above 1.0 shows less of the world
+    pub zoom: f32,
+    pub near: f32,
+    pub far: f32,
+    pub field_of_view: f32,
+    pub aspect_ratio: f32,
+}
+
+impl Camera {
+    pub fn new(aspect_ratio: f32) -> Camera {
+        Camera {
+            projection: Projection::Orthographic,
+            pos: Vec3::zero(),
+            pitch: 0.0,
+            yaw: 0.0,
+            zoom: 1.0,
+            near: 0.1,
+            far: 100.0,
+            field_of_view: 75.0,
+            aspect_ratio: aspect_ratio,
+        }
+    }
+
+    pub fn with_projection(mut self, projection: Projection) -> Camera {
+        self.projection = projection;
+        self
+    }
+
+    pub fn with_pos(mut self, pos: Vec3) -> Camera {
+        self.pos = pos;
+        self
+    }
+
+    pub fn with_pitch(mut self, pitch: f32) -> Camera {
+        self.pitch = pitch;
+        self
+    }
+
+    pub fn with_yaw(mut self, yaw: f32) -> Camera {
+        self.yaw = yaw;
+        self
+    }
+
+    pub fn with_zoom(mut self, zoom: f32) -> Camera {
+        self.zoom = zoom;
+        self
+    }
+
+    pub fn with_clipping(mut self, near: f32, far: f32) -> Camera {
+        self.near = near;
+        self.far = far;
+        self
+    }
+
+    pub fn with_field_of_view(mut self, field_of_view: f32) -> Camera {
+        self.field_of_view = field_of_view;
+        self
+    }
+
+    // both projections scale x and y by 1 / tan(field_of_view / 2), so dividing that tangent by zoom scales the picture by zoom
+    pub fn get_zoomed_field_of_view(&self) -> f32 {
+        let half = self.field_of_view * PI / 360.0;
+        ((half.tan() / self.zoom).atan() * 360.0) / PI
+    }
+
+    pub fn get_perspective(&self) -> Mat4 {
+        let field_of_view = self.get_zoomed_field_of_view();
+        match self.projection {
+            Projection::Orthographic => Mat4::orthographic(self.near, self.far, field_of_view, self.aspect_ratio),
+            Projection::Perspective => Mat4::perspective(self.near, self.far, field_of_view, self.aspect_ratio),
+        }
+    }
+
+    pub fn get_view(&self) -> Mat4 {
+        Mat4::view(self.pitch, self.yaw, self.pos)
+    }
+}
+
+impl Default for Camera {
+    fn default() -> Camera {
+        Camera::new(1.0)
+    }
+}
@@ -0,0 +1,105 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fmt;
+
+use utils::{ID};
+
+#[derive(Clone, Debug)]
+pub enum PolyclopsError {
+    WindowCreation(String),
+    ProgramCreation(String),
+    BufferCreation(String),
+    TextureDecode(String),
+    TextureCreation(String),
+    Draw(String),
+    MissingWorld(ID),
+    MissingBeing(ID),
+    MissingBase,
+    MissingEntity(u32),
+    MissingPerspective(ID),
+    MissingView(ID),
+    MissingModel(ID),
+    MissingBuffer(ID),
+    Io(String),
+    Decode(String),
+    WorldFormat(String),
+    InvalidParent(ID),
+    Recording(String),
+}
+
+impl PolyclopsError {
+    // true when the error came from an event or lookup whose target no longer exists
+    pub fn is_missing_target(&self) -> bool {
+        match *self {
+            PolyclopsError::MissingWorld(_) => true,
+            PolyclopsError::MissingBeing(_) => true,
+            PolyclopsError::MissingBase => true,
+            PolyclopsError::MissingEntity(_) => true,
+            PolyclopsError::MissingPerspective(_) => true,
+            PolyclopsError::MissingView(_) => true,
+            PolyclopsError::MissingModel(_) => true,
+            PolyclopsError::MissingBuffer(_) => true,
+            _ => false,
+        }
+    }
+
+    // true when the world refused an event that would have left it inconsistent, like an attach cycle
+    pub fn is_refused_event(&self) -> bool {
+        match *self {
+            PolyclopsError::InvalidParent(_) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Display for PolyclopsError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            PolyclopsError::WindowCreation(ref message) => write!(f, "Unable to Create Window: {}", message),
+            PolyclopsError::ProgramCreation(ref message) => write!(f, "Unable to Create Shader Program: {}", message),
+            PolyclopsError::BufferCreation(ref message) => write!(f, "Unable to Create Buffer: {}", message),
+            PolyclopsError::TextureDecode(ref message) => write!(f, "Unable to Decode Texture: {}", message),
+            PolyclopsError::TextureCreation(ref message) => write!(f, "Unable to Create Texture: {}", message),
+            PolyclopsError::Draw(ref message) => write!(f, "Unable to Draw: {}", message),
+            PolyclopsError::MissingWorld(id) => write!(f, "Unable to Find World {}", id),
+            PolyclopsError::MissingBeing(id) => write!(f, "Unable to Find Being {}", id),
+            PolyclopsError::MissingBase => write!(f, "Unable to Find Base"),
+            PolyclopsError::MissingEntity(id) => write!(f, "Unable to Find Entity {}", id),
+            PolyclopsError::MissingPerspective(id) => write!(f, "Unable to Find Perspective {}", id),
+            PolyclopsError::MissingView(id) => write!(f, "Unable to Find View {}", id),
+            PolyclopsError::MissingModel(id) => write!(f, "Unable to Find Model {}", id),
+            PolyclopsError::MissingBuffer(id) => write!(f, "Unable to Find Buffer {}", id),
+            PolyclopsError::Io(ref message) => write!(f, "Unable to Access File: {}", message),
+            PolyclopsError::Decode(ref message) => write!(f, "Unable to Decode Recording: {}", message),
+            PolyclopsError::WorldFormat(ref message) => write!(f, "Unable to Load World: {}", message),
+            PolyclopsError::InvalidParent(id) => write!(f, "Unable to Attach to {}, it would become its own ancestor", id),
+            PolyclopsError::Recording(ref message) => write!(f, "Unable to Record: {}", message),
+        }
+    }
+}
+
+impl Error for PolyclopsError {
+    fn description(&self) -> &str {
+        match *self {
+            PolyclopsError::WindowCreation(_) => "unable to create window",
+            PolyclopsError::ProgramCreation(_) => "unable to create shader program",
+            PolyclopsError::BufferCreation(_) => "unable to create buffer",
+            PolyclopsError::TextureDecode(_) => "unable to decode texture",
+            PolyclopsError::TextureCreation(_) => "unable to create texture",
+            PolyclopsError::Draw(_) => "unable to draw",
+            PolyclopsError::MissingWorld(_) => "missing world",
+            PolyclopsError::MissingBeing(_) => "missing being",
+            PolyclopsError::MissingBase => "missing base",
+            PolyclopsError::MissingEntity(_) => "missing entity",
+            PolyclopsError::MissingPerspective(_) => "missing perspective",
+            PolyclopsError::MissingView(_) => "missing view",
+            PolyclopsError::MissingModel(_) => "missing model",
+            PolyclopsError::MissingBuffer(_) => "missing buffer",
+            PolyclopsError::Io(_) => "unable to access file",
+            PolyclopsError::Decode(_) => "unable to decode recording",
+            PolyclopsError::WorldFormat(_) => "unable to load world",
+            PolyclopsError::InvalidParent(_) => "invalid parent",
+            PolyclopsError::Recording(_) => "unable to record",
+        }
+    }
+}
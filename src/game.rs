@@ -1,5 +1,6 @@
-use std::collections::{HashMap};
-use std::sync::{Arc, RwLock};
+use std::collections::{HashMap, HashSet};
+use std::mem;
+use std::sync::{Arc, RwLock, Mutex};
 use std::sync::atomic::{AtomicBool, Ordering};
 use glium::glutin::Event as WindowEvent;
 use glium::glutin::ElementState as GliumElementState;
@@ -8,12 +9,19 @@ use glium::glutin::VirtualKeyCode as GliumKeyCode;
 use scoped_threadpool::{Pool};
 use time::{precise_time_s};
 
-use utils::{ID, IDManager, IDType};
+use utils::{ID, IDManager, IDType, EntityIDType, ENTITY_ID_TYPES};
 use world::{World, WorldEvent, TickEvent, TickAfterEvent, EntityGraphicsEvent, EntityIDEvent, TransformEvent, Vec2Event, Vec3Event, get_rank_tick, get_rank_tick_after};
-use graphics::{Window, Transforms, method_to_parameters};
+use graphics::{Renderer, Transforms, Entity};
 use being::{BeingType, Being};
-use math::{Vec2};
+use math::{Vec2, Vec3, Mat4};
 use keyboard::{Keyboard};
+use game_config::{GameConfig, DropTimePolicy, MissingBeingPolicy};
+use integration::{integrate};
+use model::{compose_model, nest_model, ModelOrder};
+use error::{PolyclopsError};
+use trace::{Tracer, TraceBatch, TracePhase};
+use save::{NamedBeingType, SavedWorld, world_to_json, world_from_json};
+use record::{Encode, Decode, Reader, Record, RecordedInput, Recorder, read_header};
 
 pub struct Game<T: BeingType<T>> {
     worlds: HashMap<ID, Arc<RwLock<World<T>>>>,
@@ -25,16 +33,57 @@ pub struct Game<T: BeingType<T>> {
     keyboard: Keyboard,
     mouse_buttons: HashMap<GliumMouseButton, GliumElementState>,
     transforms: Arc<RwLock<Transforms>>,
-    manager: Arc<RwLock<IDManager>>,
+    manager: Arc<IDManager>,
     ranked_tick_events: Arc<RwLock<HashMap<u32, Arc<RwLock<Vec<TickEvent<T>>>>>>>,
     ranked_tick_after_events: Arc<RwLock<HashMap<u32, Arc<RwLock<Vec<TickAfterEvent<T>>>>>>>,
     tick_ranks: Arc<RwLock<Vec<u32>>>,
     tick_after_ranks: Arc<RwLock<Vec<u32>>>,
+    config: GameConfig,
+    accumulator: f64,
+    alpha: f32,
+    previous_states: HashMap<ID, BeingState>,
+    paused_by_focus: bool,
+    step_requested: bool,
+    requested_world_id: Arc<RwLock<Option<ID>>>,
+    recorder: Option<Recorder<T>>,
+    tracer: Option<Arc<RwLock<Tracer>>>,
+}
+
+#[derive(Copy, Clone, PartialEq)]
+struct BeingState {
+    pos: Vec3,
+    rot: Vec3,
+    sca: Vec3,
+    pivot: Vec3,
+}
+
+impl BeingState {
+    fn from_being<T: BeingType<T>>(being: &Box<Being<T>>) -> BeingState {
+        BeingState {
+            pos: being.get_pos3(),
+            rot: being.get_rot3(),
+            sca: being.get_sca3(),
+            pivot: being.get_pivot(),
+        }
+    }
+
+    fn lerp(&self, other: BeingState, alpha: f32) -> BeingState {
+        BeingState {
+            pos: self.pos.lerp(other.pos, alpha),
+            rot: self.rot.lerp(other.rot, alpha),
+            sca: self.sca.lerp(other.sca, alpha),
+            pivot: self.pivot.lerp(other.pivot, alpha),
+        }
+    }
+
+    fn to_model(&self, order: ModelOrder) -> Mat4 {
+        compose_model(self.pos, self.rot, self.sca, self.pivot, order)
+    }
 }
 
 impl<T: BeingType<T>> Game<T> {
-    pub fn new(manager: IDManager, thread_count: u32, active_world: World<T>, resolution: Vec2) -> Game<T> {
-        let manager = Arc::new(RwLock::new(manager));
+    pub fn new(manager: IDManager, thread_count: u32, active_world: World<T>, resolution: Vec2, config: GameConfig) -> Game<T> {
+        let manager = Arc::new(manager);
         let id = ID::new(manager.clone(), IDType::World);
         let mut map = HashMap::new();
         map.insert(id, Arc::new(RwLock::new(active_world)));
@@ -54,148 +103,478 @@ impl<T: BeingType<T>> Game<T> {
             ranked_tick_after_events: Arc::new(RwLock::new(HashMap::new())),
             tick_ranks: Arc::new(RwLock::new(vec!())),
             tick_after_ranks: Arc::new(RwLock::new(vec!())),
+            config: config,
+            accumulator: 0.0,
+            alpha: 0.0,
+            previous_states: HashMap::new(),
+            paused_by_focus: false,
+            step_requested: false,
+            requested_world_id: Arc::new(RwLock::new(None)),
+            recorder: None,
+            tracer: None,
+        }
+    }
+
+    pub fn get_config(&self) -> &GameConfig {
+        &self.config
+    }
+
+    pub fn get_interpolation_alpha(&self) -> f32 {
+        self.alpha
+    }
+
+    pub fn add_world(&mut self, world: World<T>) -> ID {
+        let id = ID::new(self.manager.clone(), IDType::World);
+        let mut world = world;
+        world.update_resolution(self.resolution, self.aspect_ratio);
+        self.worlds.insert(id, Arc::new(RwLock::new(world)));
+        id
+    }
+
+    pub fn get_world(&self, id: ID) -> Option<&Arc<RwLock<World<T>>>> {
+        self.worlds.get(&id)
+    }
+
+    pub fn get_active_world_id(&self) -> ID {
+        self.active_world_id
+    }
+
+    pub fn set_active_world(&mut self, id: ID) -> Result<(), PolyclopsError> {
+        if !self.worlds.contains_key(&id) {
+            return Err(PolyclopsError::MissingWorld(id));
         }
+        self.active_world_id = id;
+        self.previous_states.clear();
+        self.record_input(RecordedInput::ActiveWorld(id));
+        Ok(())
+    }
+
+    pub fn set_paused(&mut self, paused: bool) -> Result<(), PolyclopsError> {
+        let world = try!(self.worlds.get(&self.active_world_id).ok_or(PolyclopsError::MissingWorld(self.active_world_id))).clone();
+        self.record_input(RecordedInput::Paused(paused));
+        world.write().expect("Unable to Write Active World in Set Paused").set_paused(paused);
+        Ok(())
+    }
+
+    pub fn is_paused(&self) -> Result<bool, PolyclopsError> {
+        Ok(try!(self.worlds.get(&self.active_world_id).ok_or(PolyclopsError::MissingWorld(self.active_world_id))).read().expect("Unable to Read Active World in Is Paused").is_paused())
+    }
+
+    pub fn set_time_scale(&mut self, time_scale: f32) {
+        self.config.time_scale = time_scale;
+    }
+
+    pub fn get_time_scale(&self) -> f32 {
+        self.config.time_scale
+    }
+
+    // runs the next tick as if unpaused, then pauses again
+    pub fn request_step(&mut self) {
+        self.step_requested = true;
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.recorder.is_some()
+    }
+
+    // traces every executed event from here on until stop_tracing
+    pub fn start_tracing(&mut self) {
+        self.tracer = Some(Arc::new(RwLock::new(Tracer::new())));
+    }
+
+    pub fn get_tracer(&self) -> Option<&Arc<RwLock<Tracer>>> {
+        self.tracer.as_ref()
+    }
+
+    pub fn stop_tracing(&mut self) -> Option<Tracer> {
+        // clones from get_tracer may still be held, so the entries are taken out through the lock
+        self.tracer.take().map(|tracer| mem::replace(&mut *tracer.write().expect("Unable to Write Tracer in Stop Tracing"), Tracer::new()))
+    }
+
+    fn begin_trace_tick(&mut self, world_id: ID) {
+        match self.tracer {
+            Some(ref tracer) => tracer.write().expect("Unable to Write Tracer in Begin Trace Tick").begin_tick(world_id),
+            None => (),
+        }
+    }
+
+    fn record(&mut self, record: Record<T>) {
+        match self.recorder {
+            Some(ref mut recorder) => recorder.record(record),
+            None => (),
+        }
+    }
+
+    fn record_input(&mut self, input: RecordedInput) {
+        self.record(Record::Input(input));
     }
 
     fn pause(&mut self) {
-        println!("Paused");
+        if self.config.pause_on_focus_loss && self.is_paused().ok() == Some(false) {
+            self.paused_by_focus = true;
+            let result = self.set_paused(true);
+            self.handle_result(result);
+        }
     }
 
     fn resume(&mut self) {
-        println!("Resumed");
+        if self.paused_by_focus {
+            self.paused_by_focus = false;
+            let result = self.set_paused(false);
+            self.handle_result(result);
+        }
+    }
+
+    fn handle_result(&self, result: Result<(), PolyclopsError>) {
+        match result {
+            Ok(()) => (),
+            Err(error) => handle_error(self.config.missing_being_policy, error),
+        }
     }
 
-    fn update_keyboard(&mut self, key_code: GliumKeyCode, element_state: GliumElementState) {
+    fn update_keyboard(&mut self, key_code: GliumKeyCode, element_state: GliumElementState) -> Result<(), PolyclopsError> {
+        self.record_input(RecordedInput::Keyboard(key_code, element_state));
         self.keyboard.set_key_state(key_code, element_state);
-        self.worlds.get(&self.active_world_id).expect("Unable to Get Active World in Update Keyboard").write().expect("Unable to Write Active World in Update Mouse Pos").update_keyboard(key_code, element_state);
+        let world = try!(self.worlds.get(&self.active_world_id).ok_or(PolyclopsError::MissingWorld(self.active_world_id)));
+        world.write().expect("Unable to Write Active World in Update Keyboard").update_keyboard(key_code, element_state);
+        Ok(())
     }
 
-    fn update_mouse_button(&mut self, mouse_button: GliumMouseButton, element_state: GliumElementState, ) {
+    fn update_mouse_button(&mut self, mouse_button: GliumMouseButton, element_state: GliumElementState) -> Result<(), PolyclopsError> {
+        self.record_input(RecordedInput::MouseButton(mouse_button, element_state));
         self.mouse_buttons.insert(mouse_button, element_state);
-        self.worlds.get(&self.active_world_id).expect("Unable to Get Active World in Update Mouse Button").write().expect("Unable to Write Active World in Update Mouse Button").update_mouse_button(mouse_button, element_state);
+        let world = try!(self.worlds.get(&self.active_world_id).ok_or(PolyclopsError::MissingWorld(self.active_world_id)));
+        world.write().expect("Unable to Write Active World in Update Mouse Button").update_mouse_button(mouse_button, element_state);
+        Ok(())
     }
 
-    fn update_mouse_pos(&mut self, mouse_pos: (i32, i32)) {
+    fn update_mouse_pos(&mut self, mouse_pos: (i32, i32)) -> Result<(), PolyclopsError> {
+        self.record_input(RecordedInput::MousePos(mouse_pos.0, mouse_pos.1));
         let x = mouse_pos.0 as f32;
         let y = mouse_pos.1 as f32;
         self.mouse_pos = Vec2::from([x, y]);
-        self.worlds.get(&self.active_world_id).expect("Unable to Get Active World in Update Mouse Pos").write().expect("Unable to Write Active World in Update Mouse Pos").update_mouse_pos(self.mouse_pos);
+        let world = try!(self.worlds.get(&self.active_world_id).ok_or(PolyclopsError::MissingWorld(self.active_world_id)));
+        world.write().expect("Unable to Write Active World in Update Mouse Pos").update_mouse_pos(self.mouse_pos);
+        Ok(())
     }
 
-    fn update_resolution(&mut self, resolution: (u32, u32)) {
+    fn update_resolution(&mut self, resolution: (u32, u32), window: &mut Renderer) {
+        self.record_input(RecordedInput::Resolution(resolution.0, resolution.1));
+        window.set_resolution(resolution);
         let width = resolution.0 as f32;
         let height = resolution.1 as f32;
         self.resolution = Vec2::from([width, height]);
         self.aspect_ratio = width / height;
-        self.worlds.get(&self.active_world_id).expect("Unable to Get Active World in Update Resolution").write().expect("Unable to Write Active World in Update Resolution").update_resolution(self.resolution, self.aspect_ratio);
+        let world_ids: Vec<ID> = self.worlds.keys().cloned().collect();
+        for world_id in world_ids {
+            let world = self.worlds.get(&world_id).expect("Unable to Get World in Update Resolution").clone();
+            world.write().expect("Unable to Write World in Update Resolution").update_resolution(self.resolution, self.aspect_ratio);
+            let events: Vec<WorldEvent<T>> = {
+                let world = world.read().expect("Unable to Read World in Update Resolution");
+                world.get_bases().values().chain(world.get_beings().values())
+                    .flat_map(|being| being.read().expect("Unable to Read Being in Update Resolution").resize(&world, self.resolution, self.aspect_ratio))
+                    .map(WorldEvent::Tick)
+                    .collect()
+            };
+            if !events.is_empty() {
+                self.starting_events(world_id, window, 0.0, Arc::new(RwLock::new(events)));
+            }
+        }
     }
 
-    pub fn run(&mut self, starting_events: Vec<WorldEvent<T>>, window: &mut Window) {
-        self.starting_events(window, 0.0, Arc::new(RwLock::new(starting_events)));
-
-        let tps: f64 = 60.0;
-        let tps_s: f64 = 1.0 / tps;
+    pub fn run(&mut self, starting_events: Vec<WorldEvent<T>>, window: &mut Renderer) {
+        self.start(starting_events, window);
 
         let mut last_time: f64 = precise_time_s();
-        let mut delta_time: f64 = 0.0;
-
-        let mut i: f64 = last_time;
-
-        let mut frames: u64 = 0;
-        let mut ticks: u64 = 0;
+        self.accumulator = 0.0;
 
         loop {
             let now = precise_time_s();
-            delta_time += now - last_time;
+            let elapsed = now - last_time;
             last_time = now;
-            while delta_time > 0.0 {
-                for event in window.poll_events(){
-                    match event {
-                        WindowEvent::Resized(width, height) => self.update_resolution((width, height)),
-                        // WindowEvent::Moved(x, y) => {
-                        //
-                        // },
-                        WindowEvent::Closed => return,
-                        // WindowEvent::DroppedFile(path_buffer) => {
-                        //
-                        // },
-                        // WindowEvent::ReceivedCharacter(character) => {
-                        //
-                        // },
-                        WindowEvent::Focused(focused) => {
-                            if focused {
-                                self.resume();
-                            } else {
-                                self.pause();
-                            }
-                        },
-                        WindowEvent::KeyboardInput(element_state, _, virtual_key_code) => match virtual_key_code {
-                            Some(virtual_key_code) => self.update_keyboard(virtual_key_code, element_state),
-                            None => (),
-                        },
-                        WindowEvent::MouseMoved(pos) => self.update_mouse_pos(pos),
-                        // WindowEvent::MouseWheel(mouse_scroll_data) => {
-                        //
-                        // },
-                        WindowEvent::MouseInput(element_state, mouse_button) => self.update_mouse_button(mouse_button, element_state),
-                        // WindowEvent::Awakened => {
-                        //
-                        // },
-                        // WindowEvent::Refresh => {
-                        //
-                        // },
-                        // WindowEvent::Suspended(suspended) => {
-                        //
-                        // },
-                        // WindowEvent::Touch(touch) => {
-                        //
-                        // },
-                        _ => (),
-                    }
-                }
-                {
-                    let tps_f32 = tps_s as f32;
-                    let events = self.tick(tps_f32);
-                    self.expand_tick_events(events);
-                    self.execute_tick_events(tps_f32);
-                    //self.clear_tick_executions();
-                    let events = self.tick_after();
-                    self.expand_tick_after_events(events);
-                    self.execute_tick_after_events(window);
-                    //self.clear_tick_after_executions();
+            if !self.poll_events(window) {
+                return;
+            }
+            self.advance(elapsed, window);
+            let alpha = self.get_accumulated_alpha();
+            self.render(window, alpha);
+        }
+    }
+
+    // adds elapsed seconds and runs the fixed length ticks they cover, at most max_catch_up_ticks of them
+    pub fn advance(&mut self, elapsed: f64, window: &mut Renderer) -> u32 {
+        self.accumulator += elapsed;
+        let tick_length = self.config.get_tick_length();
+        let mut catch_up_ticks = 0;
+        while self.accumulator >= tick_length {
+            if catch_up_ticks >= self.config.max_catch_up_ticks {
+                match self.config.drop_time_policy {
+                    DropTimePolicy::Drop => self.accumulator %= tick_length,
+                    DropTimePolicy::Carry => (),
                 }
-                delta_time -= tps_s;
-                ticks += 1;
+                break;
+            }
+            self.step(tick_length as f32, window, false);
+            self.accumulator -= tick_length;
+            catch_up_ticks += 1;
+        }
+        catch_up_ticks
+    }
+
+    // how far into the next tick the accumulated time reaches, for interpolating the render
+    fn get_accumulated_alpha(&self) -> f32 {
+        (self.accumulator / self.config.get_tick_length()).min(1.0) as f32
+    }
+
+    pub fn run_for(&mut self, ticks: u64, window: &mut Renderer) -> bool {
+        let tick_length = self.config.get_tick_length();
+        for _ in 0..ticks {
+            if !self.poll_events(window) {
+                return false;
+            }
+            self.step(tick_length as f32, window, true);
+        }
+        true
+    }
+
+    pub fn start(&mut self, starting_events: Vec<WorldEvent<T>>, window: &mut Renderer) {
+        let world_id = self.active_world_id;
+        self.start_world(world_id, starting_events, window);
+    }
+
+    pub fn start_world(&mut self, world_id: ID, starting_events: Vec<WorldEvent<T>>, window: &mut Renderer) {
+        if self.is_recording() {
+            self.record(Record::Start(world_id, starting_events.clone()));
+        }
+        self.starting_events(world_id, window, 0.0, Arc::new(RwLock::new(starting_events)));
+    }
+
+    pub fn tick_once(&mut self, window: &mut Renderer) {
+        let tick_length = self.config.get_tick_length();
+        self.step(tick_length as f32, window, false);
+    }
+
+    pub fn step(&mut self, delta_time: f32, window: &mut Renderer, render: bool) {
+        let stepping = self.step_requested && self.is_paused().ok() == Some(true);
+        self.step_requested = false;
+        if stepping {
+            let result = self.set_paused(false);
+            self.handle_result(result);
+        }
+        let delta_time = delta_time * self.config.time_scale;
+        self.save_previous_states();
+        let mut world_ids = vec!(self.active_world_id);
+        for entry in self.worlds.iter() {
+            if *entry.0 != self.active_world_id && entry.1.read().expect("Unable to Read World in Step").is_ticking_in_background() {
+                world_ids.push(*entry.0);
             }
-            self.render(window);
-            frames += 1;
-            if now > i + 1.0 {
-                i += 1.0;
-                println!("{} {}", frames.to_string(), ticks.to_string());
-                frames = 0;
-                ticks = 0;
+        }
+        for world_id in world_ids {
+            self.step_world(world_id, delta_time, window);
+        }
+        if stepping {
+            let result = self.set_paused(true);
+            self.handle_result(result);
+        }
+        let requested_world_id = self.requested_world_id.write().expect("Unable to Write Requested World ID in Step").take();
+        match requested_world_id {
+            Some(world_id) => {
+                let result = self.set_active_world(world_id);
+                self.handle_result(result);
+            },
+            None => (),
+        }
+        self.record(Record::EndStep);
+        if render {
+            self.render(window, 1.0);
+        }
+    }
+
+    fn step_world(&mut self, world_id: ID, delta_time: f32, window: &mut Renderer) {
+        self.begin_trace_tick(world_id);
+        self.worlds.get(&world_id).expect("Unable to Get World in Step World").write().expect("Unable to Write World in Step World").deliver_messages();
+        let events = self.tick(world_id, delta_time);
+        if self.is_recording() {
+            let recorded = events.read().expect("Unable to Read Events in Step World").clone();
+            self.record(Record::Tick(world_id, delta_time, recorded));
+        }
+        self.expand_tick_events(events);
+        self.execute_tick_events(world_id, delta_time);
+        //self.clear_tick_executions();
+        let paused = self.worlds.get(&world_id).expect("Unable to Get World in Step World").read().expect("Unable to Read World in Step World").is_paused();
+        if !paused {
+            self.integrate(world_id, delta_time);
+        }
+        self.update_models(world_id);
+        let events = self.tick_after(world_id);
+        if self.is_recording() {
+            let recorded = events.read().expect("Unable to Read Events in Step World").clone();
+            self.record(Record::TickAfter(world_id, recorded));
+        }
+        self.expand_tick_after_events(events);
+        self.execute_tick_after_events(world_id, window);
+        //self.clear_tick_after_executions();
+        self.worlds.get(&world_id).expect("Unable to Get World in Step World").write().expect("Unable to Write World in Step World").update_sleeping();
+    }
+
+    pub fn poll_events(&mut self, window: &mut Renderer) -> bool {
+        for event in window.poll_events() {
+            match event {
+                WindowEvent::Resized(width, height) => self.update_resolution((width, height), window),
+                // WindowEvent::Moved(x, y) => {
+                //
+                // },
+                WindowEvent::Closed => return false,
+                // WindowEvent::DroppedFile(path_buffer) => {
+                //
+                // },
+                // WindowEvent::ReceivedCharacter(character) => {
+                //
+                // },
+                WindowEvent::Focused(focused) => {
+                    if focused {
+                        self.resume();
+                    } else {
+                        self.pause();
+                    }
+                },
+                WindowEvent::KeyboardInput(element_state, _, virtual_key_code) => match virtual_key_code {
+                    Some(virtual_key_code) => {
+                        let result = self.update_keyboard(virtual_key_code, element_state);
+                        self.handle_result(result);
+                    },
+                    None => (),
+                },
+                WindowEvent::MouseMoved(pos) => {
+                    let result = self.update_mouse_pos(pos);
+                    self.handle_result(result);
+                },
+                // WindowEvent::MouseWheel(mouse_scroll_data) => {
+                //
+                // },
+                WindowEvent::MouseInput(element_state, mouse_button) => {
+                    let result = self.update_mouse_button(mouse_button, element_state);
+                    self.handle_result(result);
+                },
+                // WindowEvent::Awakened => {
+                //
+                // },
+                // WindowEvent::Refresh => {
+                //
+                // },
+                // WindowEvent::Suspended(suspended) => {
+                //
+                // },
+                // WindowEvent::Touch(touch) => {
+                //
+                // },
+                _ => (),
             }
         }
+        true
     }
 
-    fn render(&mut self, window: &mut Window) {
+    pub fn render(&mut self, window: &mut Renderer, alpha: f32) {
+        self.alpha = alpha;
         let mut frame = window.frame();
-        for entry in self.worlds.get(&self.active_world_id).expect("Unable to Get Active World in Render").read().expect("Unable to Read World when rendering").get_beings() {
-            let being = entry.1;
-            for entity in being.read().expect("Unable to Read Being when rendering").get_entities() {
-                frame.draw_entity(entity.1, &self.transforms);
+        let world = self.worlds.get(&self.active_world_id).expect("Unable to Get Active World in Render").read().expect("Unable to Read World when rendering");
+        frame.set_camera(world.get_camera().get_perspective(), world.get_camera().get_view());
+        for entry in world.get_beings() {
+            let being = entry.1.read().expect("Unable to Read Being when rendering");
+            // only models the game computes itself can be rebuilt from pos, sca and rot, the rest are drawn as stored
+            let model = if being.has_automatic_model() {
+                Some(self.get_state(*entry.0, &being, alpha).to_model(self.config.model_order))
+            } else {
+                None
+            };
+            let parent_model = self.get_parent_model(&world, *entry.0, alpha);
+            for entity in being.get_entities() {
+                let result = match self.get_entity_model(&world, &being, *entity.0, model, parent_model) {
+                    Ok(Some(model)) => frame.draw_entity_with_model(entity.1, &self.transforms, model),
+                    Ok(None) => frame.draw_entity(entity.1, &self.transforms),
+                    Err(error) => Err(error),
+                };
+                match result {
+                    Ok(()) => (),
+                    Err(error) => handle_error(self.config.missing_being_policy, error),
+                }
             }
         }
         frame.end();
     }
 
-    fn starting_events(&mut self, window: &mut Window, delta_time: f32, events: Arc<RwLock<Vec<WorldEvent<T>>>>) {
+    fn get_state(&self, id: ID, being: &Box<Being<T>>, alpha: f32) -> BeingState {
+        let current = BeingState::from_being(being);
+        if !being.get_type().is_interpolated() {
+            return current;
+        }
+        match self.previous_states.get(&id) {
+            Some(previous) => previous.lerp(current, alpha),
+            None => current,
+        }
+    }
+
+    // every ancestor's pos, sca and rot, outermost first
+    fn get_parent_model(&self, world: &World<T>, id: ID, alpha: f32) -> Option<Mat4> {
+        let mut model: Option<Mat4> = None;
+        let mut parent = world.get_parent(id);
+        while let Some(parent_id) = parent {
+            let parent_model = match world.get_being(parent_id) {
+                Some(being) => self.get_state(parent_id, &being.read().expect("Unable to Read Parent in Get Parent Model"), alpha).to_model(self.config.model_order),
+                None => break,
+            };
+            model = Some(match model {
+                Some(model) => nest_model(parent_model, model),
+                None => parent_model,
+            });
+            parent = world.get_parent(parent_id);
+        }
+        model
+    }
+
+    // None draws the entity with its own model matrix untouched
+    fn get_entity_model(&self, world: &World<T>, being: &Box<Being<T>>, entity_id: u32, model: Option<Mat4>, parent_model: Option<Mat4>) -> Result<Option<Mat4>, PolyclopsError> {
+        // automatic models give every entity the being's model, so entity parents only apply to beings that set their own
+        let mut entity_parent = if being.has_automatic_model() {
+            None
+        } else {
+            world.get_entity_parent(being.get_id(), entity_id)
+        };
+        if entity_parent.is_none() && parent_model.is_none() {
+            return Ok(model);
+        }
+        let transforms = self.transforms.read().expect("Unable to Read Transforms in Get Entity Model");
+        let mut model = match model {
+            Some(model) => model,
+            None => try!(transforms.get_model_matrix(&try!(being.find_entity(entity_id)).read().expect("Unable to Read Entity in Get Entity Model"))),
+        };
+        while let Some(parent_id) = entity_parent {
+            let parent_matrix = try!(transforms.get_model_matrix(&try!(being.find_entity(parent_id)).read().expect("Unable to Read Parent Entity in Get Entity Model")));
+            model = nest_model(parent_matrix, model);
+            entity_parent = world.get_entity_parent(being.get_id(), parent_id);
+        }
+        Ok(Some(match parent_model {
+            Some(parent_model) => nest_model(parent_model, model),
+            None => model,
+        }))
+    }
+
+    fn save_previous_states(&mut self) {
+        self.previous_states.clear();
+        for entry in self.worlds.get(&self.active_world_id).expect("Unable to Get Active World in Save Previous States").read().expect("Unable to Read Active World in Save Previous States").get_beings() {
+            self.previous_states.insert(*entry.0, BeingState::from_being(&entry.1.read().expect("Unable to Read Being in Save Previous States")));
+        }
+    }
+
+    fn starting_events(&mut self, world_id: ID, window: &mut Renderer, delta_time: f32, events: Arc<RwLock<Vec<WorldEvent<T>>>>) {
+        self.begin_trace_tick(world_id);
         let events_split = self.split_events(events);
         self.expand_tick_events(events_split.0);
-        self.execute_tick_events(delta_time);
+        self.execute_tick_events(world_id, delta_time);
         //self.clear_tick_executions();
+        self.update_models(world_id);
         self.expand_tick_after_events(events_split.1);
-        self.execute_tick_after_events(window);
+        self.execute_tick_after_events(world_id, window);
         //self.clear_tick_after_executions();
     }
 
@@ -206,53 +585,119 @@ impl<T: BeingType<T>> Game<T> {
             let mut events = events.write().expect("Unable to Write Events in Split Events");
             let mut tick = tick.write().expect("Unable to Write Tick in Split Events");
             let mut after = after.write().expect("Unable to Write After in Split Events");
-            loop {
-                match events.pop() {
-                    Some(event) => match event {
-                        WorldEvent::Tick(event) => tick.push(event),
-                        WorldEvent::TickAfter(event) => after.push(event),
-                    },
-                    None => break,
+            for event in events.drain(..) {
+                match event {
+                    WorldEvent::Tick(event) => tick.push(event),
+                    WorldEvent::TickAfter(event) => after.push(event),
                 }
             }
         }
         (tick, after)
     }
 
-    fn tick_after(&mut self) -> Arc<RwLock<Vec<TickAfterEvent<T>>>> {
-        let events_arc: Arc<RwLock<Vec<TickAfterEvent<T>>>> = Arc::new(RwLock::new(vec!()));
-        let active_world = self.worlds.remove(&self.active_world_id).expect("Unable to Find Active World in Tick After");
+    fn tick_after(&mut self, world_id: ID) -> Arc<RwLock<Vec<TickAfterEvent<T>>>> {
+        let events_arc: Arc<RwLock<Vec<(ID, Vec<TickAfterEvent<T>>)>>> = Arc::new(RwLock::new(vec!()));
+        let active_world = self.worlds.remove(&world_id).expect("Unable to Find Active World in Tick After");
         {
             let transforms = &self.transforms;
             self.thread_pool.scoped(|scope| {
-                for entry in active_world.read().expect("Unable to Read Active World in Tick After").get_beings() {
+                let world_read = active_world.read().expect("Unable to Read Active World in Tick After");
+                for entry in world_read.get_beings() {
+                    if world_read.is_sleeping(*entry.0) {
+                        continue;
+                    }
                     let being = entry.1.clone();
                     let world = active_world.clone();
                     let events = events_arc.clone();
                     let transforms = transforms.clone();
                     scope.execute(move || {
                         let being_read = being.read().expect("Unable to Read Being in Tick After");
-                        let tick_after_events = being_read.tick_after(&world.read().expect("Unable to Read World in Tick After"), &transforms.read().expect("Unable to Read Transforms in Tick After"));
-                        let mut events = events.write().expect("Unable to Write Events in Tick After");
-                        for event in tick_after_events {
-                            events.push(event);
+                        let world = world.read().expect("Unable to Read World in Tick After");
+                        if world.is_paused() && !being_read.ticks_while_paused() {
+                            return;
                         }
+                        let tick_after_events = being_read.tick_after(&world, &transforms.read().expect("Unable to Read Transforms in Tick After"));
+                        events.write().expect("Unable to Write Events in Tick After").push((being_read.get_id(), tick_after_events));
                     });
                 }
             });
         }
-        self.worlds.insert(self.active_world_id, active_world);
-        events_arc
+        self.worlds.insert(world_id, active_world);
+        let mut being_events = events_arc.write().expect("Unable to Write Events in Tick After");
+        if self.config.deterministic {
+            being_events.sort_by(|a, b| a.0.cmp(&b.0));
+        }
+        let mut events = vec!();
+        for entry in being_events.drain(..) {
+            events.extend(entry.1);
+        }
+        Arc::new(RwLock::new(events))
     }
 
-    fn tick(&mut self, delta_time: f32) -> Arc<RwLock<Vec<TickEvent<T>>>> {
-        let events_arc: Arc<RwLock<Vec<TickEvent<T>>>> = Arc::new(RwLock::new(vec!()));
+    fn integrate(&mut self, world_id: ID, delta_time: f32) {
+        let method = self.config.integration_method;
+        let active_world = self.worlds.get(&world_id).expect("Unable to Get Active World in Integrate");
+        self.thread_pool.scoped(|scope| {
+            let world_read = active_world.read().expect("Unable to Read Active World in Integrate");
+            for entry in world_read.get_beings() {
+                if world_read.is_sleeping(*entry.0) {
+                    continue;
+                }
+                let being = entry.1.clone();
+                let world = active_world.clone();
+                let id = *entry.0;
+                scope.execute(move || {
+                    if integrate(&mut being.write().expect("Unable to Write Being in Integrate"), method, delta_time) {
+                        world.read().expect("Unable to Read World in Integrate").mark_dirty(id);
+                    }
+                });
+            }
+        });
+    }
+
+    // writes the model matrices of automatic beings marked dirty since the last update,
+    // entities still sharing their base's model are left alone so one being can't move every other
+    fn update_models(&mut self, world_id: ID) {
+        let order = self.config.model_order;
+        let world = self.worlds.get(&world_id).expect("Unable to Get World in Update Models").read().expect("Unable to Read World in Update Models");
+        let transforms = self.transforms.read().expect("Unable to Read Transforms in Update Models");
+        for id in world.take_dirty() {
+            let being = match world.get_being(id) {
+                Some(being) => being.read().expect("Unable to Read Being in Update Models"),
+                None => continue,
+            };
+            if !being.has_automatic_model() {
+                continue;
+            }
+            let state = BeingState::from_being(&being);
+            let base = world.get_base(being.get_type()).map(|base| base.read().expect("Unable to Read Base in Update Models"));
+            let entities: Vec<&Arc<RwLock<Entity>>> = being.get_entities().iter()
+                .filter(|entity| match base.as_ref().and_then(|base| base.get_entity(*entity.0)) {
+                    Some(base_entity) => base_entity.read().expect("Unable to Read Base Entity in Update Models").get_model_id() != entity.1.read().expect("Unable to Read Entity in Update Models").get_model_id(),
+                    None => true,
+                })
+                .map(|entity| entity.1)
+                .collect();
+            let model = state.to_model(order);
+            let inverse = model.to_inverse();
+            for entity in entities {
+                transforms.set_model_matrix(entity, model, inverse);
+            }
+        }
+    }
+
+    fn tick(&mut self, world_id: ID, delta_time: f32) -> Arc<RwLock<Vec<TickEvent<T>>>> {
+        let events_arc: Arc<RwLock<Vec<(ID, Vec<TickEvent<T>>)>>> = Arc::new(RwLock::new(vec!()));
         let delta_time_arc = Arc::new(delta_time);
-        let active_world = self.worlds.remove(&self.active_world_id).expect("Unable to find Active World in Tick");
+        let active_world = self.worlds.remove(&world_id).expect("Unable to find Active World in Tick");
         {
             let transforms = &self.transforms;
             self.thread_pool.scoped(|scope| {
-                for entry in active_world.read().expect("Unable to Read active word in Tick").get_beings() {
+                let world_read = active_world.read().expect("Unable to Read active word in Tick");
+                for entry in world_read.get_beings() {
+                    if world_read.is_sleeping(*entry.0) {
+                        continue;
+                    }
                     let being = entry.1.clone();
                     let dt = delta_time_arc.clone();
                     let world = active_world.clone();
@@ -260,17 +705,26 @@ impl<T: BeingType<T>> Game<T> {
                     let transforms = transforms.clone();
                     scope.execute(move || {
                         let being_read = being.read().expect("Unable to Read Being in Tick");
-                        let tick_events = being_read.tick(&world.read().expect("Unable to Read World in Tick"), &transforms.read().expect("Unable to Read Transforms in Tick"), &dt);
-                        let mut events = events.write().expect("Unable to Write Events in Tick");
-                        for event in tick_events {
-                            events.push(event);
+                        let world = world.read().expect("Unable to Read World in Tick");
+                        if world.is_paused() && !being_read.ticks_while_paused() {
+                            return;
                         }
+                        let tick_events = being_read.tick(&world, &transforms.read().expect("Unable to Read Transforms in Tick"), &dt);
+                        events.write().expect("Unable to Write Events in Tick").push((being_read.get_id(), tick_events));
                     });
                 }
             });
         }
-        self.worlds.insert(self.active_world_id, active_world);
-        events_arc
+        self.worlds.insert(world_id, active_world);
+        let mut being_events = events_arc.write().expect("Unable to Write Events in Tick");
+        if self.config.deterministic {
+            being_events.sort_by(|a, b| a.0.cmp(&b.0));
+        }
+        let mut events = vec!();
+        for entry in being_events.drain(..) {
+            events.extend(entry.1);
+        }
+        Arc::new(RwLock::new(events))
     }
 
     // fn clear_tick_after_executions(&mut self) {
@@ -289,41 +743,37 @@ impl<T: BeingType<T>> Game<T> {
 
     fn expand_tick_after_events(&mut self, events: Arc<RwLock<Vec<TickAfterEvent<T>>>>) {
         let mut events = events.write().expect("Unable to Write Events in Expand Tick After Events");
-        loop {
-            match events.pop() {
-                Some(event) => {
-                    let rank = get_rank_tick_after(event.clone());
-                    self.tick_after_ranks.write().expect("Unable to Write Tick After Ranks in Expand Tick After Events").push(rank);
-                    if !self.ranked_tick_after_events.read().expect("Unable to Read Ranked Tick After Events in Expand Tick After Events").contains_key(&rank) {
-                        self.ranked_tick_after_events.write().expect("Unable to Write Ranked Tick After Events in Expand Tick After Events").insert(rank, Arc::new(RwLock::new(vec!())));
-                    }
-                    let ranked = self.ranked_tick_after_events.read().expect("Unable to Read Ranked Tick After Events in Expand Tick After Events");
-                    ranked.get(&rank).expect("Unable to Get Rank in Expand Tick After Events").write().expect("Unable to Write Ranked Tick After Events in Expand Tick After Events").push(event);
-                },
-                None => break,
+        for event in events.drain(..) {
+            let rank = get_rank_tick_after(event.clone());
+            self.tick_after_ranks.write().expect("Unable to Write Tick After Ranks in Expand Tick After Events").push(rank);
+            if !self.ranked_tick_after_events.read().expect("Unable to Read Ranked Tick After Events in Expand Tick After Events").contains_key(&rank) {
+                self.ranked_tick_after_events.write().expect("Unable to Write Ranked Tick After Events in Expand Tick After Events").insert(rank, Arc::new(RwLock::new(vec!())));
             }
+            let ranked = self.ranked_tick_after_events.read().expect("Unable to Read Ranked Tick After Events in Expand Tick After Events");
+            ranked.get(&rank).expect("Unable to Get Rank in Expand Tick After Events").write().expect("Unable to Write Ranked Tick After Events in Expand Tick After Events").push(event);
         }
     }
 
     fn expand_tick_events(&mut self, events: Arc<RwLock<Vec<TickEvent<T>>>>) {
         let mut events = events.write().expect("Unable to Write Events in Expand Tick Events");
-        loop {
-            match events.pop() {
-                Some(event) => {
-                    let rank = get_rank_tick(event.clone());
-                    self.tick_ranks.write().expect("Unable to Write Tick Ranks in Expand Tick Events").push(rank);
-                    if !self.ranked_tick_events.read().expect("Unable to Read Ranked Tick Events in Expand Tick Events").contains_key(&rank) {
-                        self.ranked_tick_events.write().expect("Unable to Write Ranked Tick Events in Expand Tick Events").insert(rank, Arc::new(RwLock::new(vec!())));
-                    }
-                    let ranked = self.ranked_tick_events.read().expect("Unable to Read Ranked Tick Events in Expand Tick Events");
-                    ranked.get(&rank).expect("Unable to Get Rank in Expand Tick Events").write().expect("Unable to Write Ranked Tick Events in Expand Tick Events").push(event);
-                },
-                None => break,
+        for event in events.drain(..) {
+            let rank = get_rank_tick(event.clone());
+            self.tick_ranks.write().expect("Unable to Write Tick Ranks in Expand Tick Events").push(rank);
+            if !self.ranked_tick_events.read().expect("Unable to Read Ranked Tick Events in Expand Tick Events").contains_key(&rank) {
+                self.ranked_tick_events.write().expect("Unable to Write Ranked Tick Events in Expand Tick Events").insert(rank, Arc::new(RwLock::new(vec!())));
             }
+            let ranked = self.ranked_tick_events.read().expect("Unable to Read Ranked Tick Events in Expand Tick Events");
+            ranked.get(&rank).expect("Unable to Get Rank in Expand Tick Events").write().expect("Unable to Write Ranked Tick Events in Expand Tick Events").push(event);
         }
     }
 
-    fn execute_tick_after_events(&mut self, window: &mut Window) {
+    fn execute_tick_after_events(&mut self, world_id: ID, window: &mut Renderer) {
+        let policy = self.config.missing_being_policy;
+        let tracer = self.tracer.clone();
+        let world = match self.worlds.get(&world_id) {
+            Some(world) => world.clone(),
+            None => return handle_error(policy, PolyclopsError::MissingWorld(world_id)),
+        };
         {
             let mut ranks_write = self.tick_after_ranks.write().expect("unable to Write Tick After Ranks in Execute Tick After Events");
             ranks_write.sort_by(|a, b| a.cmp(b));
@@ -334,75 +784,135 @@ impl<T: BeingType<T>> Game<T> {
             match rank {
                 Some(rank) => {
                     let ranked_events = self.ranked_tick_after_events.read().expect("Unable to Read Ranked Tick After Events in Execute Tick After Events");
-                    let mut ranked_events_vec = ranked_events.get(&rank).expect("Unable to Get Rank in Execute Tick After Events").write().expect("Unable to Write Ranked Events in Execute Tick After Events");
-                    loop {
-                        match ranked_events_vec.pop() {
-                            Some(event) => {
-                                match event {
-                                    TickAfterEvent::EndBeing(id) => {
-                                        let mut world = self.worlds.get(&self.active_world_id).expect("Unable to Get Active World in Execute Events").write().expect("Unable to Write Active World in Execute Events");
-                                        world.del_being(id);
-                                    },
-                                    TickAfterEvent::Entity(being_id, entity_id, entity_event) => match entity_event {
-                                        EntityGraphicsEvent::Vertices(vertices) => {
-                                            let world = self.worlds.get(&self.active_world_id).expect("Unable to Get Active World in Execute Events").read().expect("Unable to Read Active World in Execute Events");
-                                            let being = world.get_being(being_id).expect("Unable to Get Being in Entity Vertices in Execute Events").read().expect("Unable to Read Being in Entity Vertices in Execute Events");
-                                            let entity = being.get_entity(entity_id).expect("Unable to Get Entity in Entity Vertices in Execute Events");
-                                            window.set_vertices(entity, vertices);
-                                        },
-                                        EntityGraphicsEvent::Indices(indices) => {
-                                            let world = self.worlds.get(&self.active_world_id).expect("Unable to Get Active World in Execute Events").read().expect("Unable to Read Active World in Execute Events");
-                                            let being = world.get_being(being_id).expect("Unable to Get Being in Entity Vertices in Execute Events").read().expect("Unable to Read Being in Entity Vertices in Execute Events");
-                                            let entity = being.get_entity(entity_id).expect("Unable to Get Entity in Entity Vertices in Execute Events");
-                                            window.set_indices(entity, indices);
-                                        },
-                                        EntityGraphicsEvent::Texture(texture) => {
-                                            let world = self.worlds.get(&self.active_world_id).expect("Unable to Get Active World in Execute Events").read().expect("Unable to Read Active World in Execute Events");
-                                            let being = world.get_being(being_id).expect("Unable to Get Being in Entity Vertices in Execute Events").read().expect("Unable to Read Being in Entity Vertices in Execute Events");
-                                            let entity = being.get_entity(entity_id).expect("Unable to Get Entity in Entity Vertices in Execute Events");
-                                            window.set_texture(entity, texture);
-                                        },
-                                        EntityGraphicsEvent::DrawMethod(draw_method) => {
-                                            let world = self.worlds.get(&self.active_world_id).expect("Unable to Get Active World in Execute Events").read().expect("Unable to Read Active World in Execute Events");
-                                            let being = world.get_being(being_id).expect("Unable to Get Being in Entity Vertices in Execute Events").read().expect("Unable to Read Being in Entity Vertices in Execute Events");
-                                            let entity = being.get_entity(entity_id).expect("Unable to Get Entity in Entity Vertices in Execute Events");
-                                            window.set_draw_parameters(entity, method_to_parameters(draw_method));
-                                        },
-                                    },
-                                    TickAfterEvent::EntityBase(being_type, entity_id, entity_base_event) => match entity_base_event {
-                                        EntityGraphicsEvent::Vertices(vertices) => {
-                                            let world = self.worlds.get(&self.active_world_id).expect("Unable to Get Active World in Execute Events").read().expect("Unable to Read Active World in Entity Base Vertices Execute Events");
-                                            let base = world.get_base(being_type).expect("Unable to Get Base in Entity Base Vertices in Execute Events").read().expect("Unable to Read Base in Entity Base Vertices in Execute Events");
-                                            window.set_vertices(base.get_entity(entity_id).expect("Unable to Get Entity in Entity Base Vertices in Execute Events"), vertices);
-                                        },
-                                        EntityGraphicsEvent::Indices(indices) => {
-                                            let world = self.worlds.get(&self.active_world_id).expect("Unable to Get Active World in Execute Events").read().expect("Unable to Read Active World in Entity Base Indices in Execute Events");
-                                            let base = world.get_base(being_type).expect("Unable to Get Base in Entity Base Indices in Execute Events").read().expect("Unable to Read Base in Entity Base Indices in Execute Events");
-                                            window.set_indices(base.get_entity(entity_id).expect("Unable to Get Entity in Entity Base Indices in Execute Events"), indices);
-                                        },
-                                        EntityGraphicsEvent::Texture(texture) => {
-                                            let world = self.worlds.get(&self.active_world_id).expect("Unable to Get Active World in Execute Events").read().expect("Unable to Read Active World in Entity Base Texture in Execute Events");
-                                            let base = world.get_base(being_type).expect("Unable to Get Base in Entity Base Texture in Execute Events").read().expect("Unable to Read Base in Entity Base Texture in Execute Events");
-                                            window.set_texture(base.get_entity(entity_id).expect("Unable to Get Entity in Entity Base Texture in Execute Events"), texture);
-                                        },
-                                        EntityGraphicsEvent::DrawMethod(draw_method) => {
-                                            let world = self.worlds.get(&self.active_world_id).expect("Unable to Get Active World in Execute Events").read().expect("Unable to Read Active World in Entity Base Draw Method in Execute Events");
-                                            let base = world.get_base(being_type).expect("Unable to Get Base in Entity Base Draw Method in Execute Events").read().expect("Unable to Read Base in Entity Base Draw Method in Execute Events");
-                                            window.set_draw_parameters(base.get_entity(entity_id).expect("Unable to Get Entity in Entity Base Draw Method in Execute Events"), method_to_parameters(draw_method));
-                                        },
-                                    },
-                                };
-                            },
-                            None => break,
+                    let events: Vec<TickAfterEvent<T>> = ranked_events.get(&rank).expect("Unable to Get Rank in Execute Tick After Events").write().expect("Unable to Write Ranked Events in Execute Tick After Events").drain(..).collect();
+                    let mut batch = new_trace_batch(&tracer);
+                    for event in events {
+                        match execute_traced_tick_after_event(event, rank, &mut batch, &self.manager, &world, &self.transforms, window) {
+                            Ok(()) => (),
+                            Err(error) => handle_error(policy, error),
                         }
                     }
+                    merge_trace_batch(&tracer, batch);
                 },
                 None => break,
             }
         }
     }
 
-    fn execute_tick_events(&mut self, delta_time: f32) {
+    fn execute_tick_events_deterministic(&mut self, world_id: ID) {
+        let policy = self.config.missing_being_policy;
+        let tracer = self.tracer.clone();
+        let world = self.worlds.get(&world_id).expect("Unable to Get World in Execute Events Deterministic").clone();
+        loop {
+            let rank = {
+                let mut ranks_write = self.tick_ranks.write().expect("Unable to Write Ranks in Execute Events Deterministic");
+                ranks_write.sort_by(|a, b| a.cmp(b));
+                ranks_write.dedup();
+                ranks_write.pop()
+            };
+            let rank = match rank {
+                Some(rank) => rank,
+                None => break,
+            };
+            let mut untargeted = vec!();
+            let mut targeted: Vec<(ID, Vec<TickEvent<T>>)> = vec!();
+            {
+                let ranked_events = self.ranked_tick_events.read().expect("Unable to Read Ranked Events in Execute Events Deterministic");
+                let mut events = ranked_events.get(&rank).expect("Unable to Get Rank in Execute Events Deterministic").write().expect("Unable to Write Ranked Events in Execute Events Deterministic");
+                let mut target_indices: HashMap<ID, usize> = HashMap::new();
+                // drained front to back so events keep the (being id, emission order) tick sorted them into
+                for event in events.drain(..) {
+                    match event.get_target() {
+                        Some(target) => {
+                            let index = *target_indices.entry(target).or_insert(targeted.len());
+                            if index == targeted.len() {
+                                targeted.push((target, vec!()));
+                            }
+                            targeted[index].1.push(event);
+                        },
+                        None => untargeted.push(event),
+                    }
+                }
+            }
+            let mut new_events = vec!();
+            let mut batch = new_trace_batch(&tracer);
+            for event in untargeted {
+                match execute_traced_tick_event(event, rank, &mut batch, self.manager.clone(), world.clone(), &self.transforms, &self.requested_world_id) {
+                    Ok(Some(mut events)) => new_events.append(&mut events),
+                    Ok(None) => (),
+                    Err(error) => handle_error(policy, error),
+                }
+            }
+            let (mut serial, parallel): (Vec<(ID, Vec<TickEvent<T>>)>, Vec<(ID, Vec<TickEvent<T>>)>) = targeted.into_iter().partition(|group| group.1.iter().any(|event| event.allocates_ids()));
+            serial.sort_by(|a, b| a.0.cmp(&b.0));
+            for group in serial {
+                for event in group.1 {
+                    match execute_traced_tick_event(event, rank, &mut batch, self.manager.clone(), world.clone(), &self.transforms, &self.requested_world_id) {
+                        Ok(Some(mut events)) => new_events.append(&mut events),
+                        Ok(None) => (),
+                        Err(error) => handle_error(policy, error),
+                    }
+                }
+            }
+            let parallel_events: Mutex<Vec<(ID, Vec<WorldEvent<T>>)>> = Mutex::new(vec!());
+            let parallel_batches: Mutex<Vec<TraceBatch>> = Mutex::new(vec!());
+            {
+                let manager = &self.manager;
+                let transforms = &self.transforms;
+                let requested_world_id = &self.requested_world_id;
+                let world = &world;
+                let parallel_events = &parallel_events;
+                let parallel_batches = &parallel_batches;
+                let template = &batch.as_ref().map(|batch| batch.new_empty());
+                self.thread_pool.scoped(|scope| {
+                    for group in parallel {
+                        let manager = manager.clone();
+                        scope.execute(move || {
+                            let mut group_events = vec!();
+                            let mut group_batch = template.clone();
+                            for event in group.1 {
+                                match execute_traced_tick_event(event, rank, &mut group_batch, manager.clone(), world.clone(), transforms, requested_world_id) {
+                                    Ok(Some(mut events)) => group_events.append(&mut events),
+                                    Ok(None) => (),
+                                    Err(error) => handle_error(policy, error),
+                                }
+                            }
+                            if !group_events.is_empty() {
+                                parallel_events.lock().expect("Unable to Lock Parallel Events in Execute Events Deterministic").push((group.0, group_events));
+                            }
+                            match group_batch {
+                                Some(group_batch) => parallel_batches.lock().expect("Unable to Lock Parallel Batches in Execute Events Deterministic").push(group_batch),
+                                None => (),
+                            }
+                        });
+                    }
+                });
+            }
+            // groups finish in any order, sorting by target keeps the follow up events stable
+            let mut parallel_events = parallel_events.into_inner().expect("Unable to Take Parallel Events in Execute Events Deterministic");
+            parallel_events.sort_by(|a, b| a.0.cmp(&b.0));
+            for (_, mut events) in parallel_events {
+                new_events.append(&mut events);
+            }
+            match batch {
+                Some(ref mut batch) => for group_batch in parallel_batches.into_inner().expect("Unable to Take Parallel Batches in Execute Events Deterministic") {
+                    batch.append(group_batch);
+                },
+                None => (),
+            }
+            merge_trace_batch(&tracer, batch);
+            if !new_events.is_empty() {
+                let events_split = self.split_events(Arc::new(RwLock::new(new_events)));
+                self.expand_tick_events(events_split.0);
+                self.expand_tick_after_events(events_split.1);
+            }
+        }
+    }
+
+    fn execute_tick_events(&mut self, world_id: ID, delta_time: f32) {
+        if self.config.deterministic {
+            return self.execute_tick_events_deterministic(world_id);
+        }
         {
             let mut ranks_write = self.tick_ranks.write().expect("Unable to Write Ranks for Sorting in Execute Events");
             ranks_write.sort_by(|a, b| a.cmp(b));
@@ -414,12 +924,18 @@ impl<T: BeingType<T>> Game<T> {
             let re_execute_buffer: Arc<RwLock<Vec<WorldEvent<T>>>> = Arc::new(RwLock::new(vec!()));
             {
                 let ranks = self.tick_ranks.clone();
-                let world = self.worlds.remove(&self.active_world_id).expect("Unable to Remove Active World in Execute Events");
+                let world = self.worlds.remove(&world_id).expect("Unable to Remove Active World in Execute Events");
                 let manager = &self.manager;
                 let ranked_events = &self.ranked_tick_events;
                 let executing = AtomicBool::new(true);
                 let rank_is_good = AtomicBool::new(true);
                 let transforms = &self.transforms;
+                let requested_world_id = &self.requested_world_id;
+                let policy = self.config.missing_being_policy;
+                let tracer = &self.tracer;
+                let template = &new_trace_batch(tracer);
+                let rank_batches: Mutex<Vec<TraceBatch>> = Mutex::new(vec!());
+                let rank_batches = &rank_batches;
                 self.thread_pool.scoped(|scope| {
                     let executing = &executing;
                     while executing.load(Ordering::Relaxed) {
@@ -434,327 +950,26 @@ impl<T: BeingType<T>> Game<T> {
                                         let manager = manager.clone();
                                         let transforms = transforms.clone();
                                         let re_execute_buffer = re_execute_buffer.clone();
+                                        let requested_world_id = requested_world_id.clone();
                                         scope.execute(move || {
                                             let events = events_arc.read().expect("Unable to Read Events in Execute Events");
                                             let events_vec = events.get(&rank).expect("Unable to Get Event in Execute Events");
                                             let event_option  = events_vec.write().expect("Unable to Write Events Vec in Execute Events").pop();
                                             let events_new_option = match event_option {
-                                                Some(event) => match event {
-                                                    TickEvent::NewBeing(being_type, being_args) => {
-                                                        Some(T::make_being(manager, being_type, active_world, being_args))
-                                                    },
-                                                    TickEvent::NewBase(being_type) => {
-                                                        Some(T::make_base(manager, being_type, active_world))
-                                                    },
-                                                    TickEvent::Sca2(id, vec2_event) => {
-                                                        match vec2_event {
-                                                            Vec2Event::Set(vec2) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Execute Events");
-                                                                world.get_being(id).expect("Unable to Get Being in Set Sca2 in Execute Events").write().expect("Unable to Write Being in Set Pos2 in Execute Events")
-                                                                .set_sca2(vec2);
-                                                            },
-                                                            Vec2Event::Add(vec2) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Execute Events");
-                                                                world.get_being(id).expect("Unable to Get Being in Add Sca2 in Execute Events").write().expect("Unable to Write Being in Add Pos2 in Execute Events")
-                                                                .add_sca2(vec2);
-                                                            },
-                                                            Vec2Event::Mul(vec2) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Execute Events");
-                                                                world.get_being(id).expect("Unable to Get Being in Mul Sca2 in Execute Events").write().expect("Unable to Write Being in Mul Pos2 in Execute Events")
-                                                                .mul_sca2(vec2);
-                                                            },
-                                                        };
-                                                        None
-                                                    },
-                                                    TickEvent::Sca3(id, vec3_event) => {
-                                                        match vec3_event {
-                                                            Vec3Event::Set(vec3) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Execute Events");
-                                                                world.get_being(id).expect("Unable to Get Being in Set Pos3 in Execute Events").write().expect("Unable to Write Being in Set Pos3 in Execute Events")
-                                                                .set_sca3(vec3);
-                                                            },
-                                                            Vec3Event::Add(vec3) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Execute Events");
-                                                                world.get_being(id).expect("Unable to Get Being in Add Pos3 in Execute Events").write().expect("Unable to Write Being in Add Pos3 in Execute Events")
-                                                                .add_sca3(vec3);
-                                                            },
-                                                            Vec3Event::Mul(vec3) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Execute Events");
-                                                                world.get_being(id).expect("Unable to Get Being in Mul Pos3 in Execute Events").write().expect("Unable to Write Being in Mul Pos3 in Execute Events")
-                                                                .mul_sca3(vec3);
-                                                            },
-                                                        };
-                                                        None
-                                                    },
-                                                    TickEvent::Rot2(id, vec2_event) => {
-                                                        match vec2_event {
-                                                            Vec2Event::Set(vec2) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Execute Events");
-                                                                world.get_being(id).expect("Unable to Get Being in Set Pos2 in Execute Events").write().expect("Unable to Write Being in Set Pos2 in Execute Events")
-                                                                .set_rot2(vec2);
-                                                            },
-                                                            Vec2Event::Add(vec2) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Execute Events");
-                                                                world.get_being(id).expect("Unable to Get Being in Add Pos2 in Execute Events").write().expect("Unable to Write Being in Add Pos2 in Execute Events")
-                                                                .add_rot2(vec2);
-                                                            },
-                                                            Vec2Event::Mul(vec2) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Execute Events");
-                                                                world.get_being(id).expect("Unable to Get Being in Mul Pos2 in Execute Events").write().expect("Unable to Write Being in Mul Pos2 in Execute Events")
-                                                                .mul_rot2(vec2);
-                                                            },
-                                                        };
-                                                        None
-                                                    },
-                                                    TickEvent::Rot3(id, vec3_event) => {
-                                                        match vec3_event {
-                                                            Vec3Event::Set(vec3) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Execute Events");
-                                                                world.get_being(id).expect("Unable to Get Being in Set Pos3 in Execute Events").write().expect("Unable to Write Being in Set Pos3 in Execute Events")
-                                                                .set_rot3(vec3);
-                                                            },
-                                                            Vec3Event::Add(vec3) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Execute Events");
-                                                                world.get_being(id).expect("Unable to Get Being in Add Pos3 in Execute Events").write().expect("Unable to Write Being in Add Pos3 in Execute Events")
-                                                                .add_rot3(vec3);
-                                                            },
-                                                            Vec3Event::Mul(vec3) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Execute Events");
-                                                                world.get_being(id).expect("Unable to Get Being in Mul Pos3 in Execute Events").write().expect("Unable to Write Being in Mul Pos3 in Execute Events")
-                                                                .mul_rot3(vec3);
-                                                            },
-                                                        };
-                                                        None
-                                                    },
-                                                    TickEvent::Pos2(id, vec2_event) => {
-                                                        match vec2_event {
-                                                            Vec2Event::Set(vec2) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Execute Events");
-                                                                world.get_being(id).expect("Unable to Get Being in Set Pos2 in Execute Events").write().expect("Unable to Write Being in Set Pos2 in Execute Events")
-                                                                .set_pos2(vec2);
-                                                            },
-                                                            Vec2Event::Add(vec2) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Execute Events");
-                                                                world.get_being(id).expect("Unable to Get Being in Add Pos2 in Execute Events").write().expect("Unable to Write Being in Add Pos2 in Execute Events")
-                                                                .add_pos2(vec2);
-                                                            },
-                                                            Vec2Event::Mul(vec2) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Execute Events");
-                                                                world.get_being(id).expect("Unable to Get Being in Mul Pos2 in Execute Events").write().expect("Unable to Write Being in Mul Pos2 in Execute Events")
-                                                                .mul_pos2(vec2);
-                                                            },
-                                                        };
-                                                        None
-                                                    },
-                                                    TickEvent::Pos3(id, vec3_event) => {
-                                                        match vec3_event {
-                                                            Vec3Event::Set(vec3) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Execute Events");
-                                                                world.get_being(id).expect("Unable to Get Being in Set Pos3 in Execute Events").write().expect("Unable to Write Being in Set Pos3 in Execute Events")
-                                                                .set_pos3(vec3);
-                                                            },
-                                                            Vec3Event::Add(vec3) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Execute Events");
-                                                                world.get_being(id).expect("Unable to Get Being in Add Pos3 in Execute Events").write().expect("Unable to Write Being in Add Pos3 in Execute Events")
-                                                                .add_pos3(vec3);
-                                                            },
-                                                            Vec3Event::Mul(vec3) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Execute Events");
-                                                                world.get_being(id).expect("Unable to Get Being in Mul Pos3 in Execute Events").write().expect("Unable to Write Being in Mul Pos3 in Execute Events")
-                                                                .mul_pos3(vec3);
-                                                            },
-                                                        };
-                                                        None
-                                                    },
-                                                    TickEvent::Vel2(id, vec2_event) => {
-                                                        match vec2_event {
-                                                            Vec2Event::Set(vec2) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Execute Events");
-                                                                world.get_being(id).expect("Unable to Get Being in Set Vel2 in Execute Events").write().expect("Unable to Write Being in Set Vel2 in Execute Events")
-                                                                .set_vel2(vec2);
-                                                            },
-                                                            Vec2Event::Add(vec2) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Execute Events");
-                                                                world.get_being(id).expect("Unable to Get Being in Add Vel2 in Execute Events").write().expect("Unable to Write Being in Add Vel2 in Execute Events")
-                                                                .add_vel2(vec2);
-                                                            },
-                                                            Vec2Event::Mul(vec2) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Execute Events");
-                                                                world.get_being(id).expect("Unable to Get Being in Mul Vel2 in Execute Events").write().expect("Unable to Write Being in Mul Vel2 in Execute Events")
-                                                                .mul_vel2(vec2);
-                                                            },
-                                                        };
-                                                        None
-                                                    },
-                                                    TickEvent::Vel3(id, vec3_event) => {
-                                                        match vec3_event {
-                                                            Vec3Event::Set(vec3) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Execute Events");
-                                                                world.get_being(id).expect("Unable to Get Being in Set Vel3 in Execute Events").write().expect("Unable to Write Being in Set Vel3 in Execute Events")
-                                                                .set_vel3(vec3);
-                                                            },
-                                                            Vec3Event::Add(vec3) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Execute Events");
-                                                                world.get_being(id).expect("Unable to Get Being in Add Vel3 in Execute Events").write().expect("Unable to Write Being in Add Vel3 in Execute Events")
-                                                                .add_vel3(vec3);
-                                                            },
-                                                            Vec3Event::Mul(vec3) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Execute Events");
-                                                                world.get_being(id).expect("Unable to Get Being in Mul Vel3 in Execute Events").write().expect("Unable to Write Being in Mul Vel3 in Execute Events")
-                                                                .mul_vel3(vec3);
-                                                            },
-                                                        };
-                                                        None
-                                                    },
-                                                    TickEvent::Acc2(id, vec2_event) => {
-                                                        match vec2_event {
-                                                            Vec2Event::Set(vec2) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Execute Events");
-                                                                world.get_being(id).expect("Unable to Get Being in Set Acc2 in Execute Events").write().expect("Unable to Write Being in Set Acc2 in Execute Events")
-                                                                .set_acc2(vec2);
-                                                            },
-                                                            Vec2Event::Add(vec2) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Execute Events");
-                                                                world.get_being(id).expect("Unable to Get Being in Add Acc2 in Execute Events").write().expect("Unable to Write Being in Add Acc2 in Execute Events")
-                                                                .add_acc2(vec2);
-                                                            },
-                                                            Vec2Event::Mul(vec2) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Execute Events");
-                                                                world.get_being(id).expect("Unable to Get Being in Mul Acc2 in Execute Events").write().expect("Unable to Write Being in Mul Acc2 in Execute Events")
-                                                                .mul_acc2(vec2);
-                                                            },
-                                                        };
-                                                        None
-                                                    },
-                                                    TickEvent::Acc3(id, vec3_event) => {
-                                                        match vec3_event {
-                                                            Vec3Event::Set(vec3) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Execute Events");
-                                                                world.get_being(id).expect("Unable to Get Being in Set Acc3 in Execute Events").write().expect("Unable to Write Being in Set Acc3 in Execute Events")
-                                                                .set_acc3(vec3);
-                                                            },
-                                                            Vec3Event::Add(vec3) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Execute Events");
-                                                                world.get_being(id).expect("Unable to Get Being in Add Acc3 in Execute Events").write().expect("Unable to Write Being in Add Acc3 in Execute Events")
-                                                                .add_acc3(vec3);
-                                                            },
-                                                            Vec3Event::Mul(vec3) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Execute Events");
-                                                                world.get_being(id).expect("Unable to Get Being in Mul Acc3 in Execute Events").write().expect("Unable to Write Being in Mul Acc3 in Execute Events")
-                                                                .mul_acc3(vec3);
-                                                            },
-                                                        };
-                                                        None
-                                                    },
-                                                    TickEvent::EntityID(being_id, entity_id, entity_id_event) => {
-                                                        match entity_id_event {
-                                                            EntityIDEvent::UseNewID(ids_to_change) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Entity ID Use New ID in Execute Events");
-                                                                let being = world.get_being(being_id).expect("Unable to Get Being in Entity ID Use New ID in Execute Events").read().expect("Unable to Read Being in Entity ID Use New ID in Execute Events");
-                                                                let mut entity = being.get_entity(entity_id).expect("Unable to Get Entity in Entity ID Use New ID in Execute Events").write().expect("Unable to Write Entity in Entity ID Use New ID in Execute Events");
-                                                                for id in ids_to_change {
-                                                                    entity.use_new_id(manager.clone(), id);
-                                                                }
-                                                            },
-                                                            EntityIDEvent::UseOldID(your_being_id, your_entity_id, ids_to_change) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Entity ID Use Old ID in Execute Events");
-                                                                let being = world.get_being(being_id).expect("Unable to Get Being in Entity ID Use Old ID in Execute Events").read().expect("Unable to Read Being in Entity ID Use Old ID in Execute Events");
-                                                                let mut entity = being.get_entity(entity_id).expect("Unable to Get Entity in Entity ID Use Old ID in Execute Events").write().expect("Unable to Write Entity in Entity ID Use Old ID in Execute Events");
-                                                                let your_being = world.get_being(your_being_id).expect("Unable to Get Being in Entity ID Use Old ID in Execute Events").read().expect("Unable to Read Being in Entity ID Use Old ID in Execute Events");
-                                                                let your_entity = your_being.get_entity(your_entity_id).expect("Unable to Get Entity in Entity ID use Old ID in Execute Events");
-                                                                for id in ids_to_change {
-                                                                    entity.use_old_id(your_entity, id);
-                                                                }
-                                                            },
-                                                            EntityIDEvent::UseBaseID(your_being_type, your_entity_id, ids_to_change) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Entity ID Use Base ID in Execute Events");
-                                                                let being = world.get_being(being_id).expect("Unable to Get Being in Entity ID Use Base ID in Execute Events").read().expect("Unable to Read Being in Entity ID Use Base ID in Execute Events");
-                                                                let mut entity = being.get_entity(entity_id).expect("Unable to Get Entity in Entity ID Use Base ID in Execute Events").write().expect("Unable to Write Entity in Entity ID Use Base ID in Execute Events");
-                                                                let your_being = world.get_base(your_being_type).expect("Unable to Get Base in Entity ID Use Base ID in Execute Events").read().expect("Unable to Read Being in Entity ID Use Base ID in Execute Events");
-                                                                let your_entity = your_being.get_entity(your_entity_id).expect("Unable to Get Entity in Entity ID use Base ID in Execute Events");
-                                                                for id in ids_to_change {
-                                                                    entity.use_old_id(your_entity, id);
-                                                                }
-                                                            },
-                                                        };
-                                                        None
-                                                    },
-                                                    TickEvent::EntityIDBase(being_type, entity_id, entity_id_event) => {
-                                                        match entity_id_event {
-                                                            EntityIDEvent::UseNewID(ids_to_change) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Entity ID Base Use New ID in Execute Events");
-                                                                let being = world.get_base(being_type).expect("Unable to Get Being in Entity ID Base Use New ID in Execute Events").read().expect("Unable to Read Being in Entity ID Base Use New ID in Execute Events");
-                                                                let mut entity = being.get_entity(entity_id).expect("Unable to Get Entity in Entity ID Base Use New ID in Execute Events").write().expect("Unable to Write Entity in Entity ID Base Use New ID in Execute Events");
-                                                                for id in ids_to_change {
-                                                                    entity.use_new_id(manager.clone(), id);
-                                                                }
-                                                            },
-                                                            EntityIDEvent::UseOldID(your_being_id, your_entity_id, ids_to_change) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Entity ID Base Use Old ID in Execute Events");
-                                                                let being = world.get_base(being_type).expect("Unable to Get Being in Entity ID Base Use Old ID in Execute Events").read().expect("Unable to Read Being in Entity ID Base Use Old ID in Execute Events");
-                                                                let mut entity = being.get_entity(entity_id).expect("Unable to Get Entity in Entity ID Base Use Old ID in Execute Events").write().expect("Unable to Write Entity in Entity ID Base Use Old ID in Execute Events");
-                                                                let your_being = world.get_being(your_being_id).expect("Unable to Get Being in Entity ID Base Use Old ID in Execute Events").read().expect("Unable to Read Being in Entity ID Base Use Old ID in Execute Events");
-                                                                let your_entity = your_being.get_entity(your_entity_id).expect("Unable to Get Entity in Entity ID Base use Old ID in Execute Events");
-                                                                for id in ids_to_change {
-                                                                    entity.use_old_id(your_entity, id);
-                                                                }
-                                                            },
-                                                            EntityIDEvent::UseBaseID(your_being_type, your_entity_id, ids_to_change) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Entity ID Base Use Base ID in Execute Events");
-                                                                let being = world.get_base(being_type).expect("Unable to Get Being in Entity ID Base Use Base ID in Execute Events").read().expect("Unable to Read Being in Entity ID Base Use Base ID in Execute Events");
-                                                                let mut entity = being.get_entity(entity_id).expect("Unable to Get Entity in Entity ID Base Use Base ID in Execute Events").write().expect("Unable to Write Entity in Entity ID Base Use Base ID in Execute Events");
-                                                                let your_being = world.get_base(your_being_type).expect("Unable to Get Base in Entity ID Base Use Base ID in Execute Events").read().expect("Unable to Read Being in Entity ID Base Use Base ID in Execute Events");
-                                                                let your_entity = your_being.get_entity(your_entity_id).expect("Unable to Get Entity in Entity ID Base use Base ID in Execute Events");
-                                                                for id in ids_to_change {
-                                                                    entity.use_old_id(your_entity, id);
-                                                                }
-                                                            },
-                                                        };
-                                                        None
-                                                    },
-                                                    TickEvent::Transform(being_id, entity_id, transform_event) => {
-                                                        match transform_event {
-                                                            TransformEvent::Perspective(matrix, inverse) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Execute Events");
-                                                                let being = world.get_being(being_id).expect("Unable to Get Being in Entity Vertices in Execute Events").read().expect("Unable to Read Being in Entity Vertices in Execute Events");
-                                                                let entity = being.get_entity(entity_id).expect("Unable to Get Entity in Entity Vertices in Execute Events");
-                                                                transforms.read().expect("Unable to Read Transforms in Entity Perspective in Execute Events").set_perspective_matrix(entity, matrix, inverse);
-                                                            },
-                                                            TransformEvent::View(matrix, inverse) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Execute Events");
-                                                                let being = world.get_being(being_id).expect("Unable to Get Being in Entity Vertices in Execute Events").read().expect("Unable to Read Being in Entity Vertices in Execute Events");
-                                                                let entity = being.get_entity(entity_id).expect("Unable to Get Entity in Entity Vertices in Execute Events");
-                                                                transforms.read().expect("Unable to Read Transforms in Entity View in Execute Events").set_view_matrix(entity, matrix, inverse);
-                                                            },
-                                                            TransformEvent::Model(matrix, inverse) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Execute Events");
-                                                                let being = world.get_being(being_id).expect("Unable to Get Being in Entity Model in Execute Events").read().expect("Unable to Read Being in Entity Model in Execute Events");
-                                                                let entity = being.get_entity(entity_id).expect("Unable to Get Entity in Entity Model in Execute Events");
-                                                                transforms.read().expect("Unable to Read Transforms in Entity Model in Execute Events").set_model_matrix(entity, matrix, inverse);
-                                                            },
-                                                        };
-                                                        None
-                                                    },
-                                                    TickEvent::TransformBase(being_type, entity_id, transform_event) => {
-                                                        match transform_event {
-                                                            TransformEvent::Perspective(perspective, inverse) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Entity Base Perspective in Execute Events");
-                                                                let base = world.get_base(being_type).expect("Unable to Get Base in Entity Base Perspective in Execute Events").read().expect("Unable to Read Base in Entity Base Perspective in Execute Events");
-                                                                transforms.read().expect("Unable to Read Transforms in Entity Base Perspective in Execute Events").set_perspective_matrix(base.get_entity(entity_id).expect("Unable to Get Entity in Entity Base Perspective in Execute Events"), perspective, inverse);
-                                                            },
-                                                            TransformEvent::View(view, inverse) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Entity Base View in Execute Events");
-                                                                let base = world.get_base(being_type).expect("Unable to Get Base in Entity Base View in Execute Events").read().expect("Unable to Read Base in Entity Base View in Execute Events");
-                                                                transforms.read().expect("Unable to Read Transforms Entity Base View in Execute Events").set_view_matrix(base.get_entity(entity_id).expect("Unable to Get Entity in Entity Base View in Execute Events"), view, inverse);
-                                                            },
-                                                            TransformEvent::Model(model, inverse) => {
-                                                                let world = active_world.read().expect("Unable to Read Active World in Entity Base Model in Execute Events");
-                                                                let base = world.get_base(being_type).expect("Unable to Get Base in Entity Base Model in Execute Events").read().expect("Unable to Read Base in Entity Base Model in Execute Events");
-                                                                transforms.read().expect("Unable to Read Transforms in Entity Base Model in Execute Events").set_model_matrix(base.get_entity(entity_id).expect("Unable to Get Entity in Entity Base Model in Execute Events"), model, inverse);
-                                                            },
-                                                        };
-                                                        None
-                                                    },
+                                                Some(event) => {
+                                                    let mut batch = template.clone();
+                                                    let result = execute_traced_tick_event(event, rank, &mut batch, manager, active_world, &transforms, &requested_world_id);
+                                                    match batch {
+                                                        Some(batch) => rank_batches.lock().expect("Unable to Lock Rank Batches in Execute Events").push(batch),
+                                                        None => (),
+                                                    }
+                                                    match result {
+                                                        Ok(events_new_option) => events_new_option,
+                                                        Err(error) => {
+                                                            handle_error(policy, error);
+                                                            None
+                                                        },
+                                                    }
                                                 },
                                                 None => {
                                                     rank_is_good.store(false, Ordering::Relaxed);
@@ -778,16 +993,735 @@ impl<T: BeingType<T>> Game<T> {
                             }
                         }
                         scope.join_all();
+                        // one tracer write per rank for everything its events recorded
+                        match *template {
+                            Some(ref template) => {
+                                let mut batch = template.clone();
+                                for event_batch in rank_batches.lock().expect("Unable to Lock Rank Batches in Execute Events").drain(..) {
+                                    batch.append(event_batch);
+                                }
+                                merge_trace_batch(tracer, Some(batch));
+                            },
+                            None => (),
+                        }
                     }
                 });
-                self.worlds.insert(self.active_world_id, world);
+                self.worlds.insert(world_id, world);
             }
             if re_execute.load(Ordering::Relaxed) {
                 let events_split = self.split_events(re_execute_buffer);
                 self.expand_tick_events(events_split.0);
                 self.expand_tick_after_events(events_split.1);
-                self.execute_tick_events(delta_time);
+                self.execute_tick_events(world_id, delta_time);
+            }
+        }
+    }
+}
+
+
+impl<T: BeingType<T> + Encode + Decode> Game<T> where T::Event: Encode + Decode {
+    // records starting events, tick output and input from here on until stop_recording
+    // the records name beings by id, which only come out the same on replay when ids are handed out in a fixed order
+    pub fn start_recording(&mut self) -> Result<(), PolyclopsError> {
+        if !self.config.deterministic {
+            return Err(PolyclopsError::Recording("recording needs a deterministic game".to_string()));
+        }
+        self.recorder = Some(Recorder::new());
+        Ok(())
+    }
+
+    // returns the recording so far, ready for save_recording or replay
+    pub fn stop_recording(&mut self) -> Option<Vec<u8>> {
+        self.recorder.take().map(|recorder| recorder.into_data())
+    }
+
+    // feeds a recording into this game in place of the beings' own ticks, the game should be set up like the recorded one was
+    pub fn replay(&mut self, data: &[u8], window: &mut Renderer, render: bool) -> Result<(), PolyclopsError> {
+        if !self.config.deterministic {
+            return Err(PolyclopsError::Recording("replaying needs a deterministic game".to_string()));
+        }
+        // the replayed steps would otherwise be recorded a second time, and only partly
+        let recorder = self.recorder.take();
+        let result = self.replay_records(data, window, render);
+        self.recorder = recorder;
+        result
+    }
+
+    fn replay_records(&mut self, data: &[u8], window: &mut Renderer, render: bool) -> Result<(), PolyclopsError> {
+        let mut reader = Reader::new(data);
+        try!(read_header(&mut reader));
+        while !reader.is_empty() {
+            match try!(Record::decode(&mut reader)) {
+                Record::Start(world_id, events) => {
+                    try!(self.check_world(world_id));
+                    self.start_world(world_id, events, window);
+                },
+                Record::Input(input) => match input {
+                    RecordedInput::Keyboard(key_code, element_state) => try!(self.update_keyboard(key_code, element_state)),
+                    RecordedInput::MouseButton(mouse_button, element_state) => try!(self.update_mouse_button(mouse_button, element_state)),
+                    RecordedInput::MousePos(x, y) => try!(self.update_mouse_pos((x, y))),
+                    RecordedInput::Resolution(width, height) => self.update_resolution((width, height), window),
+                    RecordedInput::Paused(paused) => try!(self.set_paused(paused)),
+                    RecordedInput::ActiveWorld(world_id) => try!(self.set_active_world(world_id)),
+                },
+                Record::Tick(world_id, delta_time, events) => {
+                    try!(self.check_world(world_id));
+                    self.begin_trace_tick(world_id);
+                    if world_id == self.active_world_id {
+                        self.save_previous_states();
+                    }
+                    self.worlds.get(&world_id).expect("Unable to Get World in Replay").write().expect("Unable to Write World in Replay").deliver_messages();
+                    self.expand_tick_events(Arc::new(RwLock::new(events)));
+                    self.execute_tick_events(world_id, delta_time);
+                    let paused = self.worlds.get(&world_id).expect("Unable to Get World in Replay").read().expect("Unable to Read World in Replay").is_paused();
+                    if !paused {
+                        self.integrate(world_id, delta_time);
+                    }
+                    self.update_models(world_id);
+                },
+                Record::TickAfter(world_id, events) => {
+                    try!(self.check_world(world_id));
+                    self.expand_tick_after_events(Arc::new(RwLock::new(events)));
+                    self.execute_tick_after_events(world_id, window);
+                    self.worlds.get(&world_id).expect("Unable to Get World in Replay").write().expect("Unable to Write World in Replay").update_sleeping();
+                },
+                Record::EndStep => {
+                    let requested_world_id = self.requested_world_id.write().expect("Unable to Write Requested World ID in Replay").take();
+                    match requested_world_id {
+                        Some(world_id) => try!(self.set_active_world(world_id)),
+                        None => (),
+                    }
+                    if render {
+                        self.render(window, 1.0);
+                    }
+                },
+            }
+        }
+        Ok(())
+    }
+
+    fn check_world(&self, world_id: ID) -> Result<(), PolyclopsError> {
+        if self.worlds.contains_key(&world_id) {
+            Ok(())
+        } else {
+            Err(PolyclopsError::MissingWorld(world_id))
+        }
+    }
+}
+
+impl<T: BeingType<T> + NamedBeingType> Game<T> {
+    pub fn save_world(&self, world_id: ID) -> Result<String, PolyclopsError> {
+        let world = try!(self.worlds.get(&world_id).ok_or(PolyclopsError::MissingWorld(world_id)));
+        let world = world.read().expect("Unable to Read World in Save World");
+        Ok(world_to_json(&world, &self.transforms.read().expect("Unable to Read Transforms in Save World")))
+    }
+
+    // rebuilds a saved world as a new world through make_base and make_being, switch to it with set_active_world
+    pub fn load_world(&mut self, data: &str, window: &mut Renderer) -> Result<ID, PolyclopsError> {
+        // the beings are made straight through make_being, a recording would replay them as NewBeing events in another order
+        if self.is_recording() {
+            return Err(PolyclopsError::Recording("worlds can not be loaded while recording".to_string()));
+        }
+        let saved: SavedWorld<T> = try!(world_from_json(data));
+        let world_id = self.add_world(World::new(self.resolution));
+        let world = self.worlds.get(&world_id).expect("Unable to Get World in Load World").clone();
+        let bases = saved.bases.into_iter().map(|being_type| WorldEvent::Tick(TickEvent::NewBase(being_type))).collect();
+        self.start_world(world_id, bases, window);
+        // the beings are made in one serial pass in saved order so parents and entity events can refer to them by index
+        let mut made_events = vec!();
+        let mut ids = vec!();
+        for saved_being in saved.beings.iter() {
+            let (id, mut events) = T::make_being(self.manager.clone(), saved_being.being_type.clone(), world.clone(), saved_being.being_args.clone());
+            if !world.read().expect("Unable to Read World in Load World").is_alive(id) {
+                return Err(PolyclopsError::WorldFormat("make being did not add the being it returned".to_string()));
+            }
+            made_events.append(&mut events);
+            ids.push(id);
+        }
+        self.starting_events(world_id, window, 0.0, Arc::new(RwLock::new(made_events)));
+        let mut events = vec!();
+        let mut parents = vec!();
+        for (saved_being, id) in saved.beings.into_iter().zip(ids.iter().cloned()) {
+            let being_type = saved_being.being_type;
+            parents.push(saved_being.parent);
+            let world = world.read().expect("Unable to Read World in Load World");
+            let being = try!(world.find_being(id)).read().expect("Unable to Read Being in Load World");
+            let base = world.get_base(being_type.clone()).map(|base| base.read().expect("Unable to Read Base in Load World"));
+            for saved_entity in saved_being.entities {
+                let entity = try!(being.find_entity(saved_entity.id)).read().expect("Unable to Read Entity in Load World");
+                let base_entity = base.as_ref().and_then(|base| base.get_entity(saved_entity.id)).map(|base_entity| base_entity.read().expect("Unable to Read Base Entity in Load World"));
+                let is_shared = |id_type: EntityIDType| match base_entity {
+                    Some(ref base_entity) => base_entity.get_id(id_type) == entity.get_id(id_type),
+                    None => false,
+                };
+                let new_ids: Vec<EntityIDType> = saved_entity.own.iter().cloned().filter(|id_type| is_shared(*id_type)).collect();
+                let base_ids: Vec<EntityIDType> = saved_entity.shared.iter().cloned().filter(|id_type| !is_shared(*id_type)).collect();
+                if !new_ids.is_empty() {
+                    events.push(WorldEvent::Tick(TickEvent::EntityID(id, saved_entity.id, EntityIDEvent::UseNewID(new_ids))));
+                }
+                if !base_ids.is_empty() {
+                    events.push(WorldEvent::Tick(TickEvent::EntityID(id, saved_entity.id, EntityIDEvent::UseBaseID(being_type.clone(), saved_entity.id, base_ids))));
+                }
+                for transform in saved_entity.transforms {
+                    events.push(WorldEvent::Tick(TickEvent::Transform(id, saved_entity.id, transform)));
+                }
+                match saved_entity.parent {
+                    Some(parent) => events.push(WorldEvent::Tick(TickEvent::AttachEntity(id, saved_entity.id, parent))),
+                    None => (),
+                }
+                if saved_entity.camera != entity.uses_camera() {
+                    events.push(WorldEvent::TickAfter(TickAfterEvent::Entity(id, saved_entity.id, EntityGraphicsEvent::UseCamera(saved_entity.camera))));
+                }
+            }
+        }
+        for (child, parent) in ids.iter().zip(parents) {
+            match parent {
+                Some(parent) => {
+                    let parent = try!(ids.get(parent).ok_or(PolyclopsError::WorldFormat("parent is not a saved being".to_string())));
+                    events.push(WorldEvent::Tick(TickEvent::Attach(*child, *parent)));
+                },
+                None => (),
+            }
+        }
+        self.start_world(world_id, events, window);
+        {
+            let mut world = world.write().expect("Unable to Write World in Load World");
+            world.set_paused(saved.paused);
+            world.set_tick_in_background(saved.tick_in_background);
+            let mut camera = saved.camera;
+            camera.aspect_ratio = world.get_aspect_ratio();
+            world.set_camera(camera);
+        }
+        Ok(world_id)
+    }
+}
+
+// frees the ids of the being's entities that nothing else in the world still points at, ids shared with the base or
+// handed to another being through UseOldID stay
+fn free_entity_ids<T: BeingType<T>>(world: &World<T>, being_id: ID, manager: &Arc<IDManager>, transforms: &Arc<RwLock<Transforms>>, window: &mut Renderer) {
+    let mut ids = HashSet::new();
+    {
+        let being = match world.get_being(being_id) {
+            Some(being) => being.read().expect("Unable to Read Being in Free Entity IDs"),
+            None => return,
+        };
+        for entity in being.get_entities().values() {
+            let entity = entity.read().expect("Unable to Read Entity in Free Entity IDs");
+            for id_type in ENTITY_ID_TYPES.iter() {
+                ids.insert((*id_type, entity.get_id(*id_type)));
+            }
+        }
+    }
+    let others = world.get_bases().values().chain(world.get_beings().iter().filter(|entry| *entry.0 != being_id).map(|entry| entry.1));
+    for other in others {
+        for entity in other.read().expect("Unable to Read Other Being in Free Entity IDs").get_entities().values() {
+            let entity = entity.read().expect("Unable to Read Other Entity in Free Entity IDs");
+            for id_type in ENTITY_ID_TYPES.iter() {
+                ids.remove(&(*id_type, entity.get_id(*id_type)));
             }
         }
     }
+    // each type has its own free list, sorting keeps the order ids are handed back out the same between runs
+    let mut ids: Vec<(EntityIDType, ID)> = ids.into_iter().collect();
+    ids.sort_by(|a, b| a.1.cmp(&b.1));
+    let transforms = transforms.read().expect("Unable to Read Transforms in Free Entity IDs");
+    for (id_type, id) in ids {
+        window.free_buffer(id_type, id);
+        transforms.free_matrix(id_type, id);
+        manager.free_id(IDType::Entity(id_type), id);
+    }
+}
+
+fn new_trace_batch(tracer: &Option<Arc<RwLock<Tracer>>>) -> Option<TraceBatch> {
+    tracer.as_ref().map(|tracer| tracer.read().expect("Unable to Read Tracer in New Trace Batch").new_batch())
+}
+
+fn merge_trace_batch(tracer: &Option<Arc<RwLock<Tracer>>>, batch: Option<TraceBatch>) {
+    match (tracer.as_ref(), batch) {
+        (Some(tracer), Some(batch)) => tracer.write().expect("Unable to Write Tracer in Merge Trace Batch").merge(batch),
+        _ => (),
+    }
+}
+
+fn execute_traced_tick_after_event<T: BeingType<T>>(event: TickAfterEvent<T>, rank: u32, batch: &mut Option<TraceBatch>, manager: &Arc<IDManager>, world: &Arc<RwLock<World<T>>>, transforms: &Arc<RwLock<Transforms>>, window: &mut Renderer) -> Result<(), PolyclopsError> {
+    match *batch {
+        Some(ref mut batch) => {
+            let name = event.get_name();
+            let target = event.get_target();
+            let start = batch.get_time();
+            let result = execute_tick_after_event(event, manager, world, transforms, window);
+            batch.record(TracePhase::TickAfter, name, target, rank, start);
+            result
+        },
+        None => execute_tick_after_event(event, manager, world, transforms, window),
+    }
+}
+
+fn execute_tick_after_event<T: BeingType<T>>(event: TickAfterEvent<T>, manager: &Arc<IDManager>, world: &Arc<RwLock<World<T>>>, transforms: &Arc<RwLock<Transforms>>, window: &mut Renderer) -> Result<(), PolyclopsError> {
+    match event {
+        TickAfterEvent::EndBeing(id) => {
+            let mut world = world.write().expect("Unable to Write Active World in Execute Events");
+            if world.is_alive(id) {
+                free_entity_ids(&world, id, manager, transforms, window);
+                world.del_being(id);
+                manager.free_id(IDType::Being, id);
+            }
+        },
+        TickAfterEvent::Entity(being_id, entity_id, entity_event) => match entity_event {
+            EntityGraphicsEvent::Vertices(vertices) => {
+                let world = world.read().expect("Unable to Read Active World in Execute Events");
+                let being = try!(world.find_being(being_id)).read().expect("Unable to Read Being in Entity Vertices in Execute Events");
+                let entity = try!(being.find_entity(entity_id));
+                try!(window.set_vertices(entity, vertices));
+            },
+            EntityGraphicsEvent::Indices(indices) => {
+                let world = world.read().expect("Unable to Read Active World in Execute Events");
+                let being = try!(world.find_being(being_id)).read().expect("Unable to Read Being in Entity Vertices in Execute Events");
+                let entity = try!(being.find_entity(entity_id));
+                try!(window.set_indices(entity, indices));
+            },
+            EntityGraphicsEvent::Texture(texture) => {
+                let world = world.read().expect("Unable to Read Active World in Execute Events");
+                let being = try!(world.find_being(being_id)).read().expect("Unable to Read Being in Entity Vertices in Execute Events");
+                let entity = try!(being.find_entity(entity_id));
+                try!(window.set_texture(entity, &texture));
+            },
+            EntityGraphicsEvent::DrawMethod(draw_method) => {
+                let world = world.read().expect("Unable to Read Active World in Execute Events");
+                let being = try!(world.find_being(being_id)).read().expect("Unable to Read Being in Entity Vertices in Execute Events");
+                let entity = try!(being.find_entity(entity_id));
+                window.set_draw_parameters(entity, draw_method);
+            },
+            EntityGraphicsEvent::UseCamera(camera) => {
+                let world = world.read().expect("Unable to Read Active World in Entity Use Camera in Execute Events");
+                let being = try!(world.find_being(being_id)).read().expect("Unable to Read Being in Entity Use Camera in Execute Events");
+                try!(being.find_entity(entity_id)).write().expect("Unable to Write Entity in Entity Use Camera in Execute Events").set_uses_camera(camera);
+            },
+        },
+        TickAfterEvent::EntityBase(being_type, entity_id, entity_base_event) => match entity_base_event {
+            EntityGraphicsEvent::Vertices(vertices) => {
+                let world = world.read().expect("Unable to Read Active World in Entity Base Vertices Execute Events");
+                let base = try!(world.find_base(being_type)).read().expect("Unable to Read Base in Entity Base Vertices in Execute Events");
+                try!(window.set_vertices(try!(base.find_entity(entity_id)), vertices));
+            },
+            EntityGraphicsEvent::Indices(indices) => {
+                let world = world.read().expect("Unable to Read Active World in Entity Base Indices in Execute Events");
+                let base = try!(world.find_base(being_type)).read().expect("Unable to Read Base in Entity Base Indices in Execute Events");
+                try!(window.set_indices(try!(base.find_entity(entity_id)), indices));
+            },
+            EntityGraphicsEvent::Texture(texture) => {
+                let world = world.read().expect("Unable to Read Active World in Entity Base Texture in Execute Events");
+                let base = try!(world.find_base(being_type)).read().expect("Unable to Read Base in Entity Base Texture in Execute Events");
+                try!(window.set_texture(try!(base.find_entity(entity_id)), &texture));
+            },
+            EntityGraphicsEvent::DrawMethod(draw_method) => {
+                let world = world.read().expect("Unable to Read Active World in Entity Base Draw Method in Execute Events");
+                let base = try!(world.find_base(being_type)).read().expect("Unable to Read Base in Entity Base Draw Method in Execute Events");
+                window.set_draw_parameters(try!(base.find_entity(entity_id)), draw_method);
+            },
+            EntityGraphicsEvent::UseCamera(camera) => {
+                let world = world.read().expect("Unable to Read Active World in Entity Base Use Camera in Execute Events");
+                let base = try!(world.find_base(being_type)).read().expect("Unable to Read Base in Entity Base Use Camera in Execute Events");
+                try!(base.find_entity(entity_id)).write().expect("Unable to Write Entity in Entity Base Use Camera in Execute Events").set_uses_camera(camera);
+            },
+        },
+    };
+    Ok(())
+}
+
+fn execute_traced_tick_event<T: BeingType<T>>(event: TickEvent<T>, rank: u32, batch: &mut Option<TraceBatch>, manager: Arc<IDManager>, world: Arc<RwLock<World<T>>>, transforms: &Arc<RwLock<Transforms>>, requested_world_id: &Arc<RwLock<Option<ID>>>) -> Result<Option<Vec<WorldEvent<T>>>, PolyclopsError> {
+    match *batch {
+        Some(ref mut batch) => {
+            let name = event.get_name();
+            let target = event.get_target();
+            let start = batch.get_time();
+            let result = execute_tick_event(event, manager, world, transforms, requested_world_id);
+            batch.record(TracePhase::Tick, name, target, rank, start);
+            result
+        },
+        None => execute_tick_event(event, manager, world, transforms, requested_world_id),
+    }
+}
+
+fn execute_tick_event<T: BeingType<T>>(event: TickEvent<T>, manager: Arc<IDManager>, world: Arc<RwLock<World<T>>>, transforms: &Arc<RwLock<Transforms>>, requested_world_id: &Arc<RwLock<Option<ID>>>) -> Result<Option<Vec<WorldEvent<T>>>, PolyclopsError> {
+    // anything aimed at a sleeping being wakes it
+    match event.get_target() {
+        Some(target) => {
+            let world = world.read().expect("Unable to Read World to Wake in Execute Events");
+            world.wake(target);
+            if event.changes_model() {
+                world.mark_dirty(target);
+            }
+        },
+        None => (),
+    }
+    match event {
+        TickEvent::NewBeing(being_type, being_args) => {
+            Ok(Some(T::make_being(manager, being_type, world, being_args).1))
+        },
+        TickEvent::NewBase(being_type) => {
+            Ok(Some(T::make_base(manager, being_type, world)))
+        },
+        TickEvent::Sca2(id, vec2_event) => {
+            match vec2_event {
+                Vec2Event::Set(vec2) => {
+                    let world = world.read().expect("Unable to Read Active World in Execute Events");
+                    try!(world.find_being(id)).write().expect("Unable to Write Being in Set Pos2 in Execute Events")
+                    .set_sca2(vec2);
+                },
+                Vec2Event::Add(vec2) => {
+                    let world = world.read().expect("Unable to Read Active World in Execute Events");
+                    try!(world.find_being(id)).write().expect("Unable to Write Being in Add Pos2 in Execute Events")
+                    .add_sca2(vec2);
+                },
+                Vec2Event::Mul(vec2) => {
+                    let world = world.read().expect("Unable to Read Active World in Execute Events");
+                    try!(world.find_being(id)).write().expect("Unable to Write Being in Mul Pos2 in Execute Events")
+                    .mul_sca2(vec2);
+                },
+            };
+            Ok(None)
+        },
+        TickEvent::Sca3(id, vec3_event) => {
+            match vec3_event {
+                Vec3Event::Set(vec3) => {
+                    let world = world.read().expect("Unable to Read Active World in Execute Events");
+                    try!(world.find_being(id)).write().expect("Unable to Write Being in Set Pos3 in Execute Events")
+                    .set_sca3(vec3);
+                },
+                Vec3Event::Add(vec3) => {
+                    let world = world.read().expect("Unable to Read Active World in Execute Events");
+                    try!(world.find_being(id)).write().expect("Unable to Write Being in Add Pos3 in Execute Events")
+                    .add_sca3(vec3);
+                },
+                Vec3Event::Mul(vec3) => {
+                    let world = world.read().expect("Unable to Read Active World in Execute Events");
+                    try!(world.find_being(id)).write().expect("Unable to Write Being in Mul Pos3 in Execute Events")
+                    .mul_sca3(vec3);
+                },
+            };
+            Ok(None)
+        },
+        TickEvent::Rot2(id, vec2_event) => {
+            match vec2_event {
+                Vec2Event::Set(vec2) => {
+                    let world = world.read().expect("Unable to Read Active World in Execute Events");
+                    try!(world.find_being(id)).write().expect("Unable to Write Being in Set Pos2 in Execute Events")
+                    .set_rot2(vec2);
+                },
+                Vec2Event::Add(vec2) => {
+                    let world = world.read().expect("Unable to Read Active World in Execute Events");
+                    try!(world.find_being(id)).write().expect("Unable to Write Being in Add Pos2 in Execute Events")
+                    .add_rot2(vec2);
+                },
+                Vec2Event::Mul(vec2) => {
+                    let world = world.read().expect("Unable to Read Active World in Execute Events");
+                    try!(world.find_being(id)).write().expect("Unable to Write Being in Mul Pos2 in Execute Events")
+                    .mul_rot2(vec2);
+                },
+            };
+            Ok(None)
+        },
+        TickEvent::Rot3(id, vec3_event) => {
+            match vec3_event {
+                Vec3Event::Set(vec3) => {
+                    let world = world.read().expect("Unable to Read Active World in Execute Events");
+                    try!(world.find_being(id)).write().expect("Unable to Write Being in Set Pos3 in Execute Events")
+                    .set_rot3(vec3);
+                },
+                Vec3Event::Add(vec3) => {
+                    let world = world.read().expect("Unable to Read Active World in Execute Events");
+                    try!(world.find_being(id)).write().expect("Unable to Write Being in Add Pos3 in Execute Events")
+                    .add_rot3(vec3);
+                },
+                Vec3Event::Mul(vec3) => {
+                    let world = world.read().expect("Unable to Read Active World in Execute Events");
+                    try!(world.find_being(id)).write().expect("Unable to Write Being in Mul Pos3 in Execute Events")
+                    .mul_rot3(vec3);
+                },
+            };
+            Ok(None)
+        },
+        TickEvent::Pos2(id, vec2_event) => {
+            match vec2_event {
+                Vec2Event::Set(vec2) => {
+                    let world = world.read().expect("Unable to Read Active World in Execute Events");
+                    try!(world.find_being(id)).write().expect("Unable to Write Being in Set Pos2 in Execute Events")
+                    .set_pos2(vec2);
+                },
+                Vec2Event::Add(vec2) => {
+                    let world = world.read().expect("Unable to Read Active World in Execute Events");
+                    try!(world.find_being(id)).write().expect("Unable to Write Being in Add Pos2 in Execute Events")
+                    .add_pos2(vec2);
+                },
+                Vec2Event::Mul(vec2) => {
+                    let world = world.read().expect("Unable to Read Active World in Execute Events");
+                    try!(world.find_being(id)).write().expect("Unable to Write Being in Mul Pos2 in Execute Events")
+                    .mul_pos2(vec2);
+                },
+            };
+            Ok(None)
+        },
+        TickEvent::Pos3(id, vec3_event) => {
+            match vec3_event {
+                Vec3Event::Set(vec3) => {
+                    let world = world.read().expect("Unable to Read Active World in Execute Events");
+                    try!(world.find_being(id)).write().expect("Unable to Write Being in Set Pos3 in Execute Events")
+                    .set_pos3(vec3);
+                },
+                Vec3Event::Add(vec3) => {
+                    let world = world.read().expect("Unable to Read Active World in Execute Events");
+                    try!(world.find_being(id)).write().expect("Unable to Write Being in Add Pos3 in Execute Events")
+                    .add_pos3(vec3);
+                },
+                Vec3Event::Mul(vec3) => {
+                    let world = world.read().expect("Unable to Read Active World in Execute Events");
+                    try!(world.find_being(id)).write().expect("Unable to Write Being in Mul Pos3 in Execute Events")
+                    .mul_pos3(vec3);
+                },
+            };
+            Ok(None)
+        },
+        TickEvent::Vel2(id, vec2_event) => {
+            match vec2_event {
+                Vec2Event::Set(vec2) => {
+                    let world = world.read().expect("Unable to Read Active World in Execute Events");
+                    try!(world.find_being(id)).write().expect("Unable to Write Being in Set Vel2 in Execute Events")
+                    .set_vel2(vec2);
+                },
+                Vec2Event::Add(vec2) => {
+                    let world = world.read().expect("Unable to Read Active World in Execute Events");
+                    try!(world.find_being(id)).write().expect("Unable to Write Being in Add Vel2 in Execute Events")
+                    .add_vel2(vec2);
+                },
+                Vec2Event::Mul(vec2) => {
+                    let world = world.read().expect("Unable to Read Active World in Execute Events");
+                    try!(world.find_being(id)).write().expect("Unable to Write Being in Mul Vel2 in Execute Events")
+                    .mul_vel2(vec2);
+                },
+            };
+            Ok(None)
+        },
+        TickEvent::Vel3(id, vec3_event) => {
+            match vec3_event {
+                Vec3Event::Set(vec3) => {
+                    let world = world.read().expect("Unable to Read Active World in Execute Events");
+                    try!(world.find_being(id)).write().expect("Unable to Write Being in Set Vel3 in Execute Events")
+                    .set_vel3(vec3);
+                },
+                Vec3Event::Add(vec3) => {
+                    let world = world.read().expect("Unable to Read Active World in Execute Events");
+                    try!(world.find_being(id)).write().expect("Unable to Write Being in Add Vel3 in Execute Events")
+                    .add_vel3(vec3);
+                },
+                Vec3Event::Mul(vec3) => {
+                    let world = world.read().expect("Unable to Read Active World in Execute Events");
+                    try!(world.find_being(id)).write().expect("Unable to Write Being in Mul Vel3 in Execute Events")
+                    .mul_vel3(vec3);
+                },
+            };
+            Ok(None)
+        },
+        TickEvent::Acc2(id, vec2_event) => {
+            match vec2_event {
+                Vec2Event::Set(vec2) => {
+                    let world = world.read().expect("Unable to Read Active World in Execute Events");
+                    try!(world.find_being(id)).write().expect("Unable to Write Being in Set Acc2 in Execute Events")
+                    .set_acc2(vec2);
+                },
+                Vec2Event::Add(vec2) => {
+                    let world = world.read().expect("Unable to Read Active World in Execute Events");
+                    try!(world.find_being(id)).write().expect("Unable to Write Being in Add Acc2 in Execute Events")
+                    .add_acc2(vec2);
+                },
+                Vec2Event::Mul(vec2) => {
+                    let world = world.read().expect("Unable to Read Active World in Execute Events");
+                    try!(world.find_being(id)).write().expect("Unable to Write Being in Mul Acc2 in Execute Events")
+                    .mul_acc2(vec2);
+                },
+            };
+            Ok(None)
+        },
+        TickEvent::Acc3(id, vec3_event) => {
+            match vec3_event {
+                Vec3Event::Set(vec3) => {
+                    let world = world.read().expect("Unable to Read Active World in Execute Events");
+                    try!(world.find_being(id)).write().expect("Unable to Write Being in Set Acc3 in Execute Events")
+                    .set_acc3(vec3);
+                },
+                Vec3Event::Add(vec3) => {
+                    let world = world.read().expect("Unable to Read Active World in Execute Events");
+                    try!(world.find_being(id)).write().expect("Unable to Write Being in Add Acc3 in Execute Events")
+                    .add_acc3(vec3);
+                },
+                Vec3Event::Mul(vec3) => {
+                    let world = world.read().expect("Unable to Read Active World in Execute Events");
+                    try!(world.find_being(id)).write().expect("Unable to Write Being in Mul Acc3 in Execute Events")
+                    .mul_acc3(vec3);
+                },
+            };
+            Ok(None)
+        },
+        TickEvent::EntityID(being_id, entity_id, entity_id_event) => {
+            match entity_id_event {
+                EntityIDEvent::UseNewID(ids_to_change) => {
+                    let world = world.read().expect("Unable to Read Active World in Entity ID Use New ID in Execute Events");
+                    let being = try!(world.find_being(being_id)).read().expect("Unable to Read Being in Entity ID Use New ID in Execute Events");
+                    let mut entity = try!(being.find_entity(entity_id)).write().expect("Unable to Write Entity in Entity ID Use New ID in Execute Events");
+                    for id in ids_to_change {
+                        entity.use_new_id(manager.clone(), id);
+                    }
+                },
+                EntityIDEvent::UseOldID(your_being_id, your_entity_id, ids_to_change) => {
+                    let world = world.read().expect("Unable to Read Active World in Entity ID Use Old ID in Execute Events");
+                    let being = try!(world.find_being(being_id)).read().expect("Unable to Read Being in Entity ID Use Old ID in Execute Events");
+                    let mut entity = try!(being.find_entity(entity_id)).write().expect("Unable to Write Entity in Entity ID Use Old ID in Execute Events");
+                    let your_being = try!(world.find_being(your_being_id)).read().expect("Unable to Read Being in Entity ID Use Old ID in Execute Events");
+                    let your_entity = try!(your_being.find_entity(your_entity_id));
+                    for id in ids_to_change {
+                        entity.use_old_id(your_entity, id);
+                    }
+                },
+                EntityIDEvent::UseBaseID(your_being_type, your_entity_id, ids_to_change) => {
+                    let world = world.read().expect("Unable to Read Active World in Entity ID Use Base ID in Execute Events");
+                    let being = try!(world.find_being(being_id)).read().expect("Unable to Read Being in Entity ID Use Base ID in Execute Events");
+                    let mut entity = try!(being.find_entity(entity_id)).write().expect("Unable to Write Entity in Entity ID Use Base ID in Execute Events");
+                    let your_being = try!(world.find_base(your_being_type)).read().expect("Unable to Read Being in Entity ID Use Base ID in Execute Events");
+                    let your_entity = try!(your_being.find_entity(your_entity_id));
+                    for id in ids_to_change {
+                        entity.use_old_id(your_entity, id);
+                    }
+                },
+            };
+            Ok(None)
+        },
+        TickEvent::EntityIDBase(being_type, entity_id, entity_id_event) => {
+            match entity_id_event {
+                EntityIDEvent::UseNewID(ids_to_change) => {
+                    let world = world.read().expect("Unable to Read Active World in Entity ID Base Use New ID in Execute Events");
+                    let being = try!(world.find_base(being_type)).read().expect("Unable to Read Being in Entity ID Base Use New ID in Execute Events");
+                    let mut entity = try!(being.find_entity(entity_id)).write().expect("Unable to Write Entity in Entity ID Base Use New ID in Execute Events");
+                    for id in ids_to_change {
+                        entity.use_new_id(manager.clone(), id);
+                    }
+                },
+                EntityIDEvent::UseOldID(your_being_id, your_entity_id, ids_to_change) => {
+                    let world = world.read().expect("Unable to Read Active World in Entity ID Base Use Old ID in Execute Events");
+                    let being = try!(world.find_base(being_type)).read().expect("Unable to Read Being in Entity ID Base Use Old ID in Execute Events");
+                    let mut entity = try!(being.find_entity(entity_id)).write().expect("Unable to Write Entity in Entity ID Base Use Old ID in Execute Events");
+                    let your_being = try!(world.find_being(your_being_id)).read().expect("Unable to Read Being in Entity ID Base Use Old ID in Execute Events");
+                    let your_entity = try!(your_being.find_entity(your_entity_id));
+                    for id in ids_to_change {
+                        entity.use_old_id(your_entity, id);
+                    }
+                },
+                EntityIDEvent::UseBaseID(your_being_type, your_entity_id, ids_to_change) => {
+                    let world = world.read().expect("Unable to Read Active World in Entity ID Base Use Base ID in Execute Events");
+                    let being = try!(world.find_base(being_type)).read().expect("Unable to Read Being in Entity ID Base Use Base ID in Execute Events");
+                    let mut entity = try!(being.find_entity(entity_id)).write().expect("Unable to Write Entity in Entity ID Base Use Base ID in Execute Events");
+                    let your_being = try!(world.find_base(your_being_type)).read().expect("Unable to Read Being in Entity ID Base Use Base ID in Execute Events");
+                    let your_entity = try!(your_being.find_entity(your_entity_id));
+                    for id in ids_to_change {
+                        entity.use_old_id(your_entity, id);
+                    }
+                },
+            };
+            Ok(None)
+        },
+        TickEvent::Transform(being_id, entity_id, transform_event) => {
+            match transform_event {
+                TransformEvent::Perspective(matrix, inverse) => {
+                    let world = world.read().expect("Unable to Read Active World in Execute Events");
+                    let being = try!(world.find_being(being_id)).read().expect("Unable to Read Being in Entity Vertices in Execute Events");
+                    let entity = try!(being.find_entity(entity_id));
+                    transforms.read().expect("Unable to Read Transforms in Entity Perspective in Execute Events").set_perspective_matrix(entity, matrix, inverse);
+                },
+                TransformEvent::View(matrix, inverse) => {
+                    let world = world.read().expect("Unable to Read Active World in Execute Events");
+                    let being = try!(world.find_being(being_id)).read().expect("Unable to Read Being in Entity Vertices in Execute Events");
+                    let entity = try!(being.find_entity(entity_id));
+                    transforms.read().expect("Unable to Read Transforms in Entity View in Execute Events").set_view_matrix(entity, matrix, inverse);
+                },
+                TransformEvent::Model(matrix, inverse) => {
+                    let world = world.read().expect("Unable to Read Active World in Execute Events");
+                    let being = try!(world.find_being(being_id)).read().expect("Unable to Read Being in Entity Model in Execute Events");
+                    let entity = try!(being.find_entity(entity_id));
+                    transforms.read().expect("Unable to Read Transforms in Entity Model in Execute Events").set_model_matrix(entity, matrix, inverse);
+                },
+            };
+            Ok(None)
+        },
+        TickEvent::SetPaused(paused) => {
+            world.write().expect("Unable to Write Active World in Set Paused in Execute Events").set_paused(paused);
+            Ok(None)
+        },
+        TickEvent::SetActiveWorld(world_id) => {
+            *requested_world_id.write().expect("Unable to Write Requested World ID in Set Active World in Execute Events") = Some(world_id);
+            Ok(None)
+        },
+        TickEvent::Message(target, message) => {
+            try!(world.write().expect("Unable to Write World in Message in Execute Events").send_message(target, message));
+            Ok(None)
+        },
+        TickEvent::Broadcast(being_type, message) => {
+            world.write().expect("Unable to Write World in Broadcast in Execute Events").broadcast_message(being_type, message);
+            Ok(None)
+        },
+        TickEvent::Attach(child, parent) => {
+            try!(world.write().expect("Unable to Write World in Attach in Execute Events").attach(child, parent));
+            Ok(None)
+        },
+        TickEvent::Detach(child) => {
+            world.write().expect("Unable to Write World in Detach in Execute Events").detach(child);
+            Ok(None)
+        },
+        TickEvent::AttachEntity(being_id, child, parent) => {
+            try!(world.write().expect("Unable to Write World in Attach Entity in Execute Events").attach_entity(being_id, child, parent));
+            Ok(None)
+        },
+        TickEvent::DetachEntity(being_id, child) => {
+            world.write().expect("Unable to Write World in Detach Entity in Execute Events").detach_entity(being_id, child);
+            Ok(None)
+        },
+        TickEvent::SetCamera(camera) => {
+            world.write().expect("Unable to Write World in Set Camera in Execute Events").set_camera(camera);
+            Ok(None)
+        },
+        TickEvent::Custom(event) => {
+            let events = T::handle_custom_event(manager, event, world);
+            if events.is_empty() {
+                Ok(None)
+            } else {
+                Ok(Some(events))
+            }
+        },
+        TickEvent::TransformBase(being_type, entity_id, transform_event) => {
+            match transform_event {
+                TransformEvent::Perspective(perspective, inverse) => {
+                    let world = world.read().expect("Unable to Read Active World in Entity Base Perspective in Execute Events");
+                    let base = try!(world.find_base(being_type)).read().expect("Unable to Read Base in Entity Base Perspective in Execute Events");
+                    transforms.read().expect("Unable to Read Transforms in Entity Base Perspective in Execute Events").set_perspective_matrix(try!(base.find_entity(entity_id)), perspective, inverse);
+                },
+                TransformEvent::View(view, inverse) => {
+                    let world = world.read().expect("Unable to Read Active World in Entity Base View in Execute Events");
+                    let base = try!(world.find_base(being_type)).read().expect("Unable to Read Base in Entity Base View in Execute Events");
+                    transforms.read().expect("Unable to Read Transforms Entity Base View in Execute Events").set_view_matrix(try!(base.find_entity(entity_id)), view, inverse);
+                },
+                TransformEvent::Model(model, inverse) => {
+                    let world = world.read().expect("Unable to Read Active World in Entity Base Model in Execute Events");
+                    let base = try!(world.find_base(being_type)).read().expect("Unable to Read Base in Entity Base Model in Execute Events");
+                    transforms.read().expect("Unable to Read Transforms in Entity Base Model in Execute Events").set_model_matrix(try!(base.find_entity(entity_id)), model, inverse);
+                },
+            };
+            Ok(None)
+        },
+    }
+}
+
+fn handle_error(policy: MissingBeingPolicy, error: PolyclopsError) {
+    match policy {
+        MissingBeingPolicy::Drop => (),
+        MissingBeingPolicy::Log => eprintln!("{}", error),
+        MissingBeingPolicy::Panic => panic!("{}", error),
+    }
 }
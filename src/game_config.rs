@@ -0,0 +1,101 @@
+use integration::{IntegrationMethod};
+use model::{ModelOrder};
+
+#[derive(Clone)]
+pub struct GameConfig {
+    pub tick_rate: f64,
+    pub max_catch_up_ticks: u32,
+    pub drop_time_policy: DropTimePolicy,
+    pub integration_method: IntegrationMethod,
+    pub pause_on_focus_loss: bool,
+    pub time_scale: f32,
+    pub missing_being_policy: MissingBeingPolicy,
+    pub deterministic: bool,
+    pub model_order: ModelOrder,
+}
+
+impl GameConfig {
+    pub fn new() -> GameConfig {
+        GameConfig {
+            tick_rate: 60.0,
+            max_catch_up_ticks: 5,
+            drop_time_policy: DropTimePolicy::Drop,
+            // beings that move themselves in tick opt out through is_integrated
+            integration_method: IntegrationMethod::SemiImplicitEuler,
+            pause_on_focus_loss: true,
+            time_scale: 1.0,
+            missing_being_policy: MissingBeingPolicy::Log,
+            deterministic: false,
+            model_order: ModelOrder::TranslationScalationRotation,
+        }
+    }
+
+    pub fn with_tick_rate(mut self, tick_rate: f64) -> GameConfig {
+        self.tick_rate = tick_rate;
+        self
+    }
+
+    pub fn with_max_catch_up_ticks(mut self, max_catch_up_ticks: u32) -> GameConfig {
+        self.max_catch_up_ticks = max_catch_up_ticks;
+        self
+    }
+
+    pub fn with_drop_time_policy(mut self, drop_time_policy: DropTimePolicy) -> GameConfig {
+        self.drop_time_policy = drop_time_policy;
+        self
+    }
+
+    pub fn with_integration_method(mut self, integration_method: IntegrationMethod) -> GameConfig {
+        self.integration_method = integration_method;
+        self
+    }
+
+    pub fn with_pause_on_focus_loss(mut self, pause_on_focus_loss: bool) -> GameConfig {
+        self.pause_on_focus_loss = pause_on_focus_loss;
+        self
+    }
+
+    pub fn with_time_scale(mut self, time_scale: f32) -> GameConfig {
+        self.time_scale = time_scale;
+        self
+    }
+
+    pub fn with_missing_being_policy(mut self, missing_being_policy: MissingBeingPolicy) -> GameConfig {
+        self.missing_being_policy = missing_being_policy;
+        self
+    }
+
+    // orders events within a rank by emitting being and applies events on the same being serially
+    pub fn with_deterministic(mut self, deterministic: bool) -> GameConfig {
+        self.deterministic = deterministic;
+        self
+    }
+
+    // how automatic model matrices are composed from each being's pos, sca and rot
+    pub fn with_model_order(mut self, model_order: ModelOrder) -> GameConfig {
+        self.model_order = model_order;
+        self
+    }
+
+    pub fn get_tick_length(&self) -> f64 {
+        1.0 / self.tick_rate
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum DropTimePolicy {
+    // forget the time that could not be caught up this frame, keeping only the fraction of a tick used for interpolation
+    Drop,
+    // keep the time and try to catch up on later frames
+    Carry,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum MissingBeingPolicy {
+    // silently skip events that fail, like those whose target no longer exists
+    Drop,
+    // skip the event and print the error to stderr
+    Log,
+    // panic with the error, the old behaviour
+    Panic,
+}
@@ -1,7 +1,8 @@
-use glium::backend::glutin_backend::{GlutinFacade, PollEventsIter};
+use glium::backend::glutin_backend::{GlutinFacade};
 use glium::texture::texture2d::{Texture2d};
 use glium::texture::{RawImage2d};
 use glium::glutin::{WindowBuilder, get_primary_monitor};
+use glium::glutin::Event as WindowEvent;
 use glium::{Surface, DisplayBuild, Program, VertexBuffer, IndexBuffer, DrawParameters};
 use glium;
 use image::{load_from_memory};
@@ -10,9 +11,43 @@ use std::sync::{Arc, RwLock};
 
 use math::{Mat4, Vec2, Vec3, Vec4};
 use utils::{ID, IDType, EntityIDType, IDManager};
+use error::{PolyclopsError};
 
 pub type Index = u32;
 
+pub trait Renderer {
+    fn get_resolution_vec2(&self) -> Vec2;
+    // called by the game when the window is resized, frames drawn afterwards cover the new size
+    fn set_resolution(&mut self, resolution: (u32, u32));
+    fn poll_events(&mut self) -> Vec<WindowEvent>;
+    fn frame<'a>(&'a mut self) -> Box<Frame + 'a>;
+    fn set_vertices(&mut self, &Arc<RwLock<Entity>>, Vec<Vertex>) -> Result<(), PolyclopsError>;
+    fn set_indices(&mut self, &Arc<RwLock<Entity>>, Vec<Index>) -> Result<(), PolyclopsError>;
+    fn set_texture(&mut self, &Arc<RwLock<Entity>>, &[u8]) -> Result<(), PolyclopsError>;
+    fn set_draw_parameters(&mut self, &Arc<RwLock<Entity>>, DrawMethod);
+    // drops whatever buffer was uploaded for the id, matrix ids are freed through Transforms instead
+    fn free_buffer(&mut self, EntityIDType, ID);
+}
+
+pub trait Frame {
+    fn draw_entity(&mut self, entity: &Arc<RwLock<Entity>>, transforms: &Arc<RwLock<Transforms>>) -> Result<(), PolyclopsError> {
+        let model = try!(transforms.read().expect("Unable to Read Transforms in Draw Entity in Frame").get_model_matrix(&entity.read().expect("Unable to Read Entity in Draw Entity in Frame")));
+        self.draw_entity_with_model(entity, transforms, model)
+    }
+    fn draw_entity_with_model(&mut self, &Arc<RwLock<Entity>>, &Arc<RwLock<Transforms>>, Mat4) -> Result<(), PolyclopsError>;
+    // perspective and view for every following entity that uses the camera
+    fn set_camera(&mut self, perspective: Mat4, view: Mat4);
+    fn end(self: Box<Self>);
+}
+
+// the frame's camera unless the entity has opted out of it, like a hud layer with its own projection
+pub fn get_perspective_view(entity: &Entity, transforms: &Transforms, camera: Option<(Mat4, Mat4)>) -> Result<(Mat4, Mat4), PolyclopsError> {
+    match camera {
+        Some(camera) if entity.uses_camera() => Ok(camera),
+        _ => Ok((try!(transforms.get_perspective_matrix(entity)), try!(transforms.get_view_matrix(entity)))),
+    }
+}
+
 pub struct Window {
     facade: GlutinFacade,
     program: Program,
@@ -24,7 +59,7 @@ pub struct Window {
 }
 
 impl Window {
-    pub fn new(args: WindowArgs) -> Window {
+    pub fn new(args: WindowArgs) -> Result<Window, PolyclopsError> {
         let vertex_shader_src = r#"
             #version 140
 
@@ -66,10 +101,9 @@ impl Window {
                     .with_decorations(true)
                     .with_depth_buffer(24)
                     .with_vsync()
-                    .build_glium()
-                    .expect("Unable to make Facade");
-                facade.get_window()
-                    .expect("Unable to find the Window")
+                    .build_glium();
+                let facade = try!(facade.map_err(|err| PolyclopsError::WindowCreation(format!("{:?}", err))));
+                try!(facade.get_window().ok_or(PolyclopsError::WindowCreation("Unable to find the Window".to_string())))
                     .set_position(((resolution.0 - width) / 2) as i32, ((resolution.1 - height) / 2) as i32);
                 facade
             },
@@ -80,52 +114,83 @@ impl Window {
                     .with_decorations(false)
                     .with_depth_buffer(24)
                     .with_vsync()
-                    .build_glium()
-                    .expect("Unable to make Facade");
-                facade.get_window()
-                    .expect("Unable to find Window")
+                    .build_glium();
+                let facade = try!(facade.map_err(|err| PolyclopsError::WindowCreation(format!("{:?}", err))));
+                try!(facade.get_window().ok_or(PolyclopsError::WindowCreation("Unable to find Window".to_string())))
                     .set_position(0, 0);
                 facade
             },
         };
-        Window {
-            program: Program::from_source(&facade, vertex_shader_src, fragment_shader_src, None).expect("Unable to make Shader Program"),
+        let program = try!(Program::from_source(&facade, vertex_shader_src, fragment_shader_src, None).map_err(|err| PolyclopsError::ProgramCreation(format!("{:?}", err))));
+        Ok(Window {
+            program: program,
             facade: facade,
             texture_buffers: HashMap::new(),
             vertex_buffers: HashMap::new(),
             index_buffers: HashMap::new(),
             draw_parameters: HashMap::new(),
             resolution: resolution,
-        }
+        })
     }
+}
 
-    pub fn get_resolution_vec2(&self) -> Vec2 {
+impl Renderer for Window {
+    fn get_resolution_vec2(&self) -> Vec2 {
         Vec2::from([self.resolution.0 as f32, self.resolution.1 as f32])
     }
 
-    pub fn frame(&mut self) -> Frame {
-        Frame::new(&mut self.facade, &mut self.program, &mut self.texture_buffers, &mut self.vertex_buffers, &mut self.index_buffers, &mut self.draw_parameters)
+    // glium frames always draw to the whole window, so only the reported resolution needs to change
+    fn set_resolution(&mut self, resolution: (u32, u32)) {
+        self.resolution = resolution;
+    }
+
+    fn poll_events(&mut self) -> Vec<WindowEvent> {
+        self.facade.poll_events().collect()
+    }
+
+    fn frame<'a>(&'a mut self) -> Box<Frame + 'a> {
+        Box::new(WindowFrame::new(&mut self.facade, &mut self.program, &mut self.texture_buffers, &mut self.vertex_buffers, &mut self.index_buffers, &mut self.draw_parameters))
     }
 
-    pub fn poll_events(&self) -> PollEventsIter {
-        self.facade.poll_events()
+    fn set_vertices(&mut self, entity: &Arc<RwLock<Entity>>, vertices: Vec<Vertex>) -> Result<(), PolyclopsError> {
+        let vertex_buffer = try!(VertexBuffer::new(&self.facade, &vertices).map_err(|err| PolyclopsError::BufferCreation(format!("{:?}", err))));
+        self.vertex_buffers.insert(entity.read().expect("Unable to Read Entity in Set Vertices").vertex_id, vertex_buffer);
+        Ok(())
     }
 
-    pub fn set_vertices(&mut self, entity: &Arc<RwLock<Entity>>, vertices: Vec<Vertex>) {
-        self.vertex_buffers.insert(entity.read().expect("Unable to Read Entity in Set Vertices").vertex_id, VertexBuffer::new(&self.facade, &vertices).expect("Failed to Create Vertex Buffer"));
+    fn set_indices(&mut self, entity: &Arc<RwLock<Entity>>, indices: Vec<Index>) -> Result<(), PolyclopsError> {
+        let index_buffer = try!(IndexBuffer::new(&self.facade, glium::index::PrimitiveType::TrianglesList, &indices).map_err(|err| PolyclopsError::BufferCreation(format!("{:?}", err))));
+        self.index_buffers.insert(entity.read().expect("Unable to Read Entity in Set Indices").index_id, index_buffer);
+        Ok(())
     }
 
-    pub fn set_indices(&mut self, entity: &Arc<RwLock<Entity>>, indices: Vec<Index>) {
-        self.index_buffers.insert(entity.read().expect("Unable to Read Entity in Set Indices").index_id, IndexBuffer::new(&self.facade, glium::index::PrimitiveType::TrianglesList, &indices).expect("Failed to Create Index Buffer"));
+    fn set_texture(&mut self, entity: &Arc<RwLock<Entity>>, data: &[u8]) -> Result<(), PolyclopsError> {
+        let texture = try!(load_from_memory(data).map_err(|err| PolyclopsError::TextureDecode(format!("{:?}", err)))).to_rgba();
+        let texture_buffer = try!(Texture2d::new(&self.facade, RawImage2d::from_raw_rgba_reversed(texture.clone().into_raw(), texture.dimensions())).map_err(|err| PolyclopsError::TextureCreation(format!("{:?}", err))));
+        self.texture_buffers.insert(entity.read().expect("Unable to Read Entity in Set Texture").texture_id, texture_buffer);
+        Ok(())
     }
 
-    pub fn set_texture(&mut self, entity: &Arc<RwLock<Entity>>, data: &[u8]) {
-        let texture = load_from_memory(data).expect("Error Loading Image").to_rgba();
-        self.texture_buffers.insert(entity.read().expect("Unable to Read Entity in Set Texture").texture_id, Texture2d::new(&self.facade, RawImage2d::from_raw_rgba_reversed(texture.clone().into_raw(), texture.dimensions())).expect("Unable to make Texture"));
+    fn set_draw_parameters(&mut self, entity: &Arc<RwLock<Entity>>, draw_method: DrawMethod) {
+        self.draw_parameters.insert(entity.read().expect("Unable to Read Entity in Set Draw Parameters").draw_parameters_id, method_to_parameters(draw_method));
     }
 
-    pub fn set_draw_parameters(&mut self, entity: &Arc<RwLock<Entity>>, draw_parameters: DrawParameters<'static>) {
-        self.draw_parameters.insert(entity.read().expect("Unable to Read Entity in Set Draw Parameters").draw_parameters_id, draw_parameters);
+    fn free_buffer(&mut self, id_type: EntityIDType, id: ID) {
+        match id_type {
+            EntityIDType::Vertex => {
+                self.vertex_buffers.remove(&id);
+            },
+            EntityIDType::Index => {
+                self.index_buffers.remove(&id);
+            },
+            EntityIDType::Texture => {
+                self.texture_buffers.remove(&id);
+            },
+            EntityIDType::DrawParameter => {
+                self.draw_parameters.remove(&id);
+            },
+            EntityIDType::Perspective | EntityIDType::View | EntityIDType::Model => (),
+        }
     }
 }
 
@@ -207,16 +272,17 @@ pub enum WindowArgs {
     Borderless(String),
 }
 
-pub struct Frame<'a> {
+pub struct WindowFrame<'a> {
     program: &'a mut Program,
     texture_buffers: &'a mut HashMap<ID, Texture2d>,
     vertex_buffers: &'a mut HashMap<ID, VertexBuffer<Vertex>>,
     index_buffers: &'a mut HashMap<ID, IndexBuffer<Index>>,
     draw_parameters: &'a mut HashMap<ID, DrawParameters<'static>>,
     frame: glium::Frame,
+    camera: Option<(Mat4, Mat4)>,
 }
 
-impl<'a> Frame<'a> {
+impl<'a> WindowFrame<'a> {
     fn new(
         facade: &'a mut GlutinFacade,
         program: &'a mut Program,
@@ -224,36 +290,44 @@ impl<'a> Frame<'a> {
         vertex_buffers: &'a mut HashMap<ID, VertexBuffer<Vertex>>,
         index_buffers: &'a mut HashMap<ID, IndexBuffer<Index>>,
         draw_parameters: &'a mut HashMap<ID, DrawParameters<'static>>,
-    ) -> Frame<'a> {
+    ) -> WindowFrame<'a> {
         let mut frame = facade.draw();
         frame.clear_color_and_depth((0.0, 0.0, 0.0, 1.0), 1.0);
-        Frame {
+        WindowFrame {
             frame: frame,
             program: program,
             texture_buffers: texture_buffers,
             vertex_buffers: vertex_buffers,
             index_buffers: index_buffers,
             draw_parameters: draw_parameters,
+            camera: None,
         }
     }
+}
 
-    pub fn draw_entity(&mut self, entity_arc: &Arc<RwLock<Entity>>, transforms: &Arc<RwLock<Transforms>>) {
+impl<'a> Frame for WindowFrame<'a> {
+    fn draw_entity_with_model(&mut self, entity_arc: &Arc<RwLock<Entity>>, transforms: &Arc<RwLock<Transforms>>, model: Mat4) -> Result<(), PolyclopsError> {
         let entity = entity_arc.read().expect("Unable to Read Entity in Draw Entity");
+        let (perspective, view) = try!(get_perspective_view(&entity, &transforms.read().expect("Unable to Read Transforms in Draw Entity in Frame"), self.camera));
         self.frame.draw(
-            self.vertex_buffers.get(&entity.vertex_id).expect("Unable to Get Vertex Buffer in Draw Entity"),
-            self.index_buffers.get(&entity.index_id).expect("Unable to Get Index Buffer in Draw Entity"),
+            try!(self.vertex_buffers.get(&entity.vertex_id).ok_or(PolyclopsError::MissingBuffer(entity.vertex_id))),
+            try!(self.index_buffers.get(&entity.index_id).ok_or(PolyclopsError::MissingBuffer(entity.index_id))),
             &self.program,
             &uniform!(
-                tex: self.texture_buffers.get(&entity.texture_id).expect("Unable to Get Texture Buffer in Draw Entity"),
-                perspective: transforms.read().expect("Unable to Read Transforms in Draw Entity in Frame").get_perspective_matrix(&entity),
-                view: transforms.read().expect("Unable to Read Transforms in Draw Entity In Frame").get_view_matrix(&entity),
-                model: transforms.read().expect("Unable to Read Transforms in Draw Entity in Frame").get_model_matrix(&entity),
+                tex: try!(self.texture_buffers.get(&entity.texture_id).ok_or(PolyclopsError::MissingBuffer(entity.texture_id))),
+                perspective: perspective,
+                view: view,
+                model: model,
             ),
-            self.draw_parameters.get(&entity.draw_parameters_id).expect("Unable to Get Draw Parameter in Draw Entity"))
-            .expect("Unable to draw Entity");
+            try!(self.draw_parameters.get(&entity.draw_parameters_id).ok_or(PolyclopsError::MissingBuffer(entity.draw_parameters_id))))
+            .map_err(|err| PolyclopsError::Draw(format!("{:?}", err)))
     }
 
-    pub fn end(self) {
+    fn set_camera(&mut self, perspective: Mat4, view: Mat4) {
+        self.camera = Some((perspective, view));
+    }
+
+    fn end(self: Box<Self>) {
         self.frame.finish().expect("Unable to Finish Frame");
     }
 }
@@ -279,56 +353,76 @@ impl Transforms {
         }
     }
 
-    pub fn backwards2(&self, vec2: Vec2, entity: &Entity) -> Vec2 {
-        Vec2::from(self.get_perspective_inverse(entity) * self.get_view_inverse(entity) * self.get_model_inverse(entity) * vec2.to_vec4(0.0, 0.0))
+    pub fn backwards2(&self, vec2: Vec2, entity: &Entity) -> Result<Vec2, PolyclopsError> {
+        Ok(Vec2::from(try!(self.get_perspective_inverse(entity)) * try!(self.get_view_inverse(entity)) * try!(self.get_model_inverse(entity)) * vec2.to_vec4(0.0, 0.0)))
     }
 
-    pub fn backwards3(&self, vec3: Vec3, entity: &Entity) -> Vec3 {
-        Vec3::from(self.get_perspective_inverse(entity) * self.get_view_inverse(entity) * self.get_model_inverse(entity) * vec3.to_vec4(0.0))
+    pub fn backwards3(&self, vec3: Vec3, entity: &Entity) -> Result<Vec3, PolyclopsError> {
+        Ok(Vec3::from(try!(self.get_perspective_inverse(entity)) * try!(self.get_view_inverse(entity)) * try!(self.get_model_inverse(entity)) * vec3.to_vec4(0.0)))
     }
 
-    pub fn backwards4(&self, vec4: Vec4, entity: &Entity) -> Vec4 {
-        self.get_perspective_inverse(entity) * self.get_view_inverse(entity) * self.get_model_inverse(entity) * vec4
+    pub fn backwards4(&self, vec4: Vec4, entity: &Entity) -> Result<Vec4, PolyclopsError> {
+        Ok(try!(self.get_perspective_inverse(entity)) * try!(self.get_view_inverse(entity)) * try!(self.get_model_inverse(entity)) * vec4)
     }
 
-    pub fn get_perspective_matrix(&self, entity: &Entity) -> Mat4 {
-        *self.perspective_mat4s.read().expect("Unable to Read Perspective Matrix in Transforms").get(&entity.perspective_id).expect("Unable to Get Perspective in Get Perspective")
+    pub fn get_perspective_matrix(&self, entity: &Entity) -> Result<Mat4, PolyclopsError> {
+        self.perspective_mat4s.read().expect("Unable to Read Perspective Matrix in Transforms").get(&entity.perspective_id).cloned().ok_or(PolyclopsError::MissingPerspective(entity.perspective_id))
     }
 
-    pub fn get_perspective_inverse(&self, entity: &Entity) -> Mat4 {
-        *self.perspective_mat4s_inverse.read().expect("Unable to Read Perspective Inverse in Transforms").get(&entity.perspective_id).expect("Unable to Get Perspective Inverse in Get Perspective Inverse")
+    pub fn get_perspective_inverse(&self, entity: &Entity) -> Result<Mat4, PolyclopsError> {
+        self.perspective_mat4s_inverse.read().expect("Unable to Read Perspective Inverse in Transforms").get(&entity.perspective_id).cloned().ok_or(PolyclopsError::MissingPerspective(entity.perspective_id))
     }
 
     pub fn set_perspective_matrix(&self, entity: &Arc<RwLock<Entity>>, perspective: Mat4, inverse: Mat4) {
-        self.perspective_mat4s.write().expect("Unable to Write Perspective Matrix in Set Perspective Matrix in Transforms").insert(entity.read().expect("Unable to Read Entity in Set Perspective Matrix").perspective_id, perspective);
-        self.perspective_mat4s_inverse.write().expect("Unable to Write Perspective Inverse in Set Perspective Matrix in Transforms").insert(entity.read().expect("Unable to Read Entity in Set Perspective Matrix").perspective_id, inverse);
+        let id = entity.read().expect("Unable to Read Entity in Set Perspective Matrix").perspective_id;
+        set_matrix(&self.perspective_mat4s, &self.perspective_mat4s_inverse, id, perspective, inverse);
     }
 
-    pub fn get_view_matrix(&self, entity: &Entity) -> Mat4 {
-        *self.view_mat4s.read().expect("Unable to Read View Matrix in Get View Matrix in Transforms").get(&entity.view_id).expect("Unable to Get View in Get View")
+    pub fn get_view_matrix(&self, entity: &Entity) -> Result<Mat4, PolyclopsError> {
+        self.view_mat4s.read().expect("Unable to Read View Matrix in Get View Matrix in Transforms").get(&entity.view_id).cloned().ok_or(PolyclopsError::MissingView(entity.view_id))
     }
 
-    pub fn get_view_inverse(&self, entity: &Entity) -> Mat4 {
-        *self.view_mat4s_inverse.read().expect("Unable to Read View Inverse in Get View Inverse in Transforms").get(&entity.view_id).expect("Unable to Get View Inverse in Get View Inverse")
+    pub fn get_view_inverse(&self, entity: &Entity) -> Result<Mat4, PolyclopsError> {
+        self.view_mat4s_inverse.read().expect("Unable to Read View Inverse in Get View Inverse in Transforms").get(&entity.view_id).cloned().ok_or(PolyclopsError::MissingView(entity.view_id))
     }
 
     pub fn set_view_matrix(&self, entity: &Arc<RwLock<Entity>>, view: Mat4, inverse: Mat4) {
-        self.view_mat4s.write().expect("Unable to Write View Matrix in Set View Matrix in Transforms").insert(entity.read().expect("Unable to Read Entity in Set View Matrix").view_id, view);
-        self.view_mat4s_inverse.write().expect("Unable to Write View Inverse in Set View Matrix in Transforms").insert(entity.read().expect("Unable to Read Entity in Set View Matrix").view_id, inverse);
+        let id = entity.read().expect("Unable to Read Entity in Set View Matrix").view_id;
+        set_matrix(&self.view_mat4s, &self.view_mat4s_inverse, id, view, inverse);
     }
 
-    pub fn get_model_matrix(&self, entity: &Entity) -> Mat4 {
-        *self.model_mat4s.read().expect("Unable to Read Model Matrix in Get Model Matrix in Transforms").get(&entity.model_id).expect("Unable to Get Model in Get Model")
+    pub fn get_model_matrix(&self, entity: &Entity) -> Result<Mat4, PolyclopsError> {
+        self.model_mat4s.read().expect("Unable to Read Model Matrix in Get Model Matrix in Transforms").get(&entity.model_id).cloned().ok_or(PolyclopsError::MissingModel(entity.model_id))
     }
 
-    pub fn get_model_inverse(&self, entity: &Entity) -> Mat4 {
-        *self.model_mat4s_inverse.read().expect("Unable to Read Model Inverse in Get Model Inverse in Transforms").get(&entity.model_id).expect("Unable to Get Model Inverse in Get Model Inverse")
+    pub fn get_model_inverse(&self, entity: &Entity) -> Result<Mat4, PolyclopsError> {
+        self.model_mat4s_inverse.read().expect("Unable to Read Model Inverse in Get Model Inverse in Transforms").get(&entity.model_id).cloned().ok_or(PolyclopsError::MissingModel(entity.model_id))
     }
 
     pub fn set_model_matrix(&self, entity: &Arc<RwLock<Entity>>, model: Mat4, inverse: Mat4) {
-        self.model_mat4s.write().expect("Unable to Write Model Matrix in Set Model Matrix in Transforms").insert(entity.read().expect("Unable to Read Entity in Set Model Matrix").model_id, model);
-        self.model_mat4s_inverse.write().expect("Unable to Write Model Inverse in Set Model Matrix in Transforms").insert(entity.read().expect("Unable to Read Entity in Set Model Matrix").model_id, inverse);
+        let id = entity.read().expect("Unable to Read Entity in Set Model Matrix").model_id;
+        set_matrix(&self.model_mat4s, &self.model_mat4s_inverse, id, model, inverse);
+    }
+
+    pub fn free_matrix(&self, id_type: EntityIDType, id: ID) {
+        let (matrices, inverses) = match id_type {
+            EntityIDType::Perspective => (&self.perspective_mat4s, &self.perspective_mat4s_inverse),
+            EntityIDType::View => (&self.view_mat4s, &self.view_mat4s_inverse),
+            EntityIDType::Model => (&self.model_mat4s, &self.model_mat4s_inverse),
+            EntityIDType::Vertex | EntityIDType::Index | EntityIDType::Texture | EntityIDType::DrawParameter => return,
+        };
+        matrices.write().expect("Unable to Write Matrices in Free Matrix").remove(&id);
+        inverses.write().expect("Unable to Write Inverses in Free Matrix").remove(&id);
+    }
+}
+
+// setting a matrix to the value it already has skips both writes, the compare only needs a read lock
+fn set_matrix(matrices: &RwLock<HashMap<ID, Mat4>>, inverses: &RwLock<HashMap<ID, Mat4>>, id: ID, matrix: Mat4, inverse: Mat4) {
+    if matrices.read().expect("Unable to Read Matrices in Set Matrix").get(&id) == Some(&matrix) {
+        return;
     }
+    matrices.write().expect("Unable to Write Matrices in Set Matrix").insert(id, matrix);
+    inverses.write().expect("Unable to Write Inverses in Set Matrix").insert(id, inverse);
 }
 
 pub struct Entity {
@@ -339,10 +433,11 @@ pub struct Entity {
     perspective_id: ID,
     view_id: ID,
     model_id: ID,
+    camera: bool,
 }
 
 impl Entity {
-    pub fn new(manager: Arc<RwLock<IDManager>>) -> Entity {
+    pub fn new(manager: Arc<IDManager>) -> Entity {
         Entity {
             texture_id: ID::new(manager.clone(), IDType::Entity(EntityIDType::Texture)),
             vertex_id: ID::new(manager.clone(), IDType::Entity(EntityIDType::Vertex)),
@@ -351,6 +446,7 @@ impl Entity {
             perspective_id: ID::new(manager.clone(), IDType::Entity(EntityIDType::Perspective)),
             view_id: ID::new(manager.clone(), IDType::Entity(EntityIDType::View)),
             model_id: ID::new(manager.clone(), IDType::Entity(EntityIDType::Model)),
+            camera: true,
         }
     }
 
@@ -364,6 +460,55 @@ impl Entity {
             perspective_id: entity.perspective_id,
             view_id: entity.view_id,
             model_id: entity.model_id,
+            camera: entity.camera,
+        }
+    }
+
+    pub fn get_texture_id(&self) -> ID {
+        self.texture_id
+    }
+
+    pub fn get_vertex_id(&self) -> ID {
+        self.vertex_id
+    }
+
+    pub fn get_index_id(&self) -> ID {
+        self.index_id
+    }
+
+    pub fn get_draw_parameters_id(&self) -> ID {
+        self.draw_parameters_id
+    }
+
+    pub fn get_perspective_id(&self) -> ID {
+        self.perspective_id
+    }
+
+    pub fn get_view_id(&self) -> ID {
+        self.view_id
+    }
+
+    pub fn get_model_id(&self) -> ID {
+        self.model_id
+    }
+
+    pub fn uses_camera(&self) -> bool {
+        self.camera
+    }
+
+    pub fn set_uses_camera(&mut self, camera: bool) {
+        self.camera = camera;
+    }
+
+    pub fn get_id(&self, id_type: EntityIDType) -> ID {
+        match id_type {
+            EntityIDType::Vertex => self.vertex_id,
+            EntityIDType::Index => self.index_id,
+            EntityIDType::Texture => self.texture_id,
+            EntityIDType::DrawParameter => self.draw_parameters_id,
+            EntityIDType::Perspective => self.perspective_id,
+            EntityIDType::View => self.view_id,
+            EntityIDType::Model => self.model_id,
         }
     }
 
@@ -394,7 +539,7 @@ impl Entity {
         };
     }
 
-    pub fn use_new_id(&mut self, manager: Arc<RwLock<IDManager>>, id_type: EntityIDType) {
+    pub fn use_new_id(&mut self, manager: Arc<IDManager>, id_type: EntityIDType) {
         let id = ID::new(manager, IDType::Entity(id_type));
         match id_type {
             EntityIDType::Vertex => {
@@ -435,6 +580,14 @@ impl Vertex {
             tex_coord: tex_coord,
         }
     }
+
+    pub fn get_position(&self) -> [f32; 3] {
+        self.position
+    }
+
+    pub fn get_tex_coord(&self) -> [f32; 2] {
+        self.tex_coord
+    }
 }
 
 impl From<Vec2> for Vertex {
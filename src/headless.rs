@@ -0,0 +1,130 @@
+use glium::glutin::Event as WindowEvent;
+use std::sync::{Arc, RwLock};
+
+use graphics::{Renderer, Frame, Entity, Transforms, Vertex, Index, DrawMethod, get_perspective_view};
+use math::{Mat4, Vec2};
+use utils::{ID, EntityIDType};
+use error::{PolyclopsError};
+
+#[derive(Clone)]
+pub enum HeadlessCall {
+    Vertices(ID, Vec<Vertex>),
+    Indices(ID, Vec<Index>),
+    Texture(ID, Vec<u8>),
+    DrawMethod(ID, DrawMethod),
+    FreeBuffer(EntityIDType, ID),
+    DrawEntity(ID, Mat4, Mat4, Mat4),
+    EndFrame,
+}
+
+pub struct Headless {
+    resolution: Vec2,
+    events: Vec<WindowEvent>,
+    calls: Vec<HeadlessCall>,
+    frames: u64,
+}
+
+impl Headless {
+    pub fn new(resolution: Vec2) -> Headless {
+        Headless {
+            resolution: resolution,
+            events: vec!(),
+            calls: vec!(),
+            frames: 0,
+        }
+    }
+
+    pub fn push_event(&mut self, event: WindowEvent) {
+        self.events.push(event);
+    }
+
+    pub fn get_calls(&self) -> &Vec<HeadlessCall> {
+        &self.calls
+    }
+
+    pub fn clear_calls(&mut self) {
+        self.calls.clear();
+    }
+
+    pub fn get_frame_count(&self) -> u64 {
+        self.frames
+    }
+}
+
+impl Renderer for Headless {
+    fn get_resolution_vec2(&self) -> Vec2 {
+        self.resolution
+    }
+
+    fn set_resolution(&mut self, resolution: (u32, u32)) {
+        self.resolution = Vec2::from([resolution.0 as f32, resolution.1 as f32]);
+    }
+
+    fn poll_events(&mut self) -> Vec<WindowEvent> {
+        self.events.drain(..).collect()
+    }
+
+    fn frame<'a>(&'a mut self) -> Box<Frame + 'a> {
+        Box::new(HeadlessFrame {
+            calls: &mut self.calls,
+            frames: &mut self.frames,
+            camera: None,
+        })
+    }
+
+    fn set_vertices(&mut self, entity: &Arc<RwLock<Entity>>, vertices: Vec<Vertex>) -> Result<(), PolyclopsError> {
+        let id = entity.read().expect("Unable to Read Entity in Set Vertices in Headless").get_vertex_id();
+        self.calls.push(HeadlessCall::Vertices(id, vertices));
+        Ok(())
+    }
+
+    fn set_indices(&mut self, entity: &Arc<RwLock<Entity>>, indices: Vec<Index>) -> Result<(), PolyclopsError> {
+        let id = entity.read().expect("Unable to Read Entity in Set Indices in Headless").get_index_id();
+        self.calls.push(HeadlessCall::Indices(id, indices));
+        Ok(())
+    }
+
+    fn set_texture(&mut self, entity: &Arc<RwLock<Entity>>, data: &[u8]) -> Result<(), PolyclopsError> {
+        let id = entity.read().expect("Unable to Read Entity in Set Texture in Headless").get_texture_id();
+        self.calls.push(HeadlessCall::Texture(id, data.to_vec()));
+        Ok(())
+    }
+
+    fn free_buffer(&mut self, id_type: EntityIDType, id: ID) {
+        self.calls.push(HeadlessCall::FreeBuffer(id_type, id));
+    }
+
+    fn set_draw_parameters(&mut self, entity: &Arc<RwLock<Entity>>, draw_method: DrawMethod) {
+        let id = entity.read().expect("Unable to Read Entity in Set Draw Parameters in Headless").get_draw_parameters_id();
+        self.calls.push(HeadlessCall::DrawMethod(id, draw_method));
+    }
+}
+
+pub struct HeadlessFrame<'a> {
+    calls: &'a mut Vec<HeadlessCall>,
+    frames: &'a mut u64,
+    camera: Option<(Mat4, Mat4)>,
+}
+
+impl<'a> Frame for HeadlessFrame<'a> {
+    fn draw_entity_with_model(&mut self, entity_arc: &Arc<RwLock<Entity>>, transforms: &Arc<RwLock<Transforms>>, model: Mat4) -> Result<(), PolyclopsError> {
+        let entity = entity_arc.read().expect("Unable to Read Entity in Draw Entity in Headless");
+        let (perspective, view) = try!(get_perspective_view(&entity, &transforms.read().expect("Unable to Read Transforms in Draw Entity in Headless"), self.camera));
+        self.calls.push(HeadlessCall::DrawEntity(
+            entity.get_model_id(),
+            perspective,
+            view,
+            model,
+        ));
+        Ok(())
+    }
+
+    fn set_camera(&mut self, perspective: Mat4, view: Mat4) {
+        self.camera = Some((perspective, view));
+    }
+
+    fn end(self: Box<Self>) {
+        self.calls.push(HeadlessCall::EndFrame);
+        *self.frames += 1;
+    }
+}
@@ -0,0 +1,39 @@
+use being::{Being, BeingType};
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum IntegrationMethod {
+    None,
+    ExplicitEuler,
+    SemiImplicitEuler,
+    Verlet,
+}
+
+// returns whether the being moved, so its model gets rebuilt
+pub fn integrate<T: BeingType<T>>(being: &mut Box<Being<T>>, method: IntegrationMethod, delta_time: f32) -> bool {
+    if !being.is_integrated() {
+        return false;
+    }
+    let pos = being.get_pos3();
+    let vel = being.get_vel3();
+    let acc = being.get_acc3();
+    let (pos, vel) = match method {
+        IntegrationMethod::None => return false,
+        IntegrationMethod::ExplicitEuler => (pos + vel * delta_time, vel + acc * delta_time),
+        IntegrationMethod::SemiImplicitEuler => {
+            let vel = vel + acc * delta_time;
+            (pos + vel * delta_time, vel)
+        },
+        // velocity verlet, acceleration only changes through events so it is constant across the tick
+        IntegrationMethod::Verlet => (pos + vel * delta_time + acc * (0.5 * delta_time * delta_time), vel + acc * delta_time),
+    };
+    let damping = being.get_damping();
+    let vel = if damping > 0.0 {
+        vel * (-damping * delta_time).exp()
+    } else {
+        vel
+    };
+    let moved = pos != being.get_pos3();
+    being.set_pos3(pos);
+    being.set_vel3(vel);
+    moved
+}
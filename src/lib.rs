@@ -5,6 +5,7 @@ extern crate image;
 extern crate scoped_threadpool;
 extern crate rand;
 extern crate num;
+extern crate rustc_serialize;
 
 mod math;
 mod graphics;
@@ -14,15 +15,37 @@ mod game;
 mod being;
 mod keyboard;
 mod being_args;
+mod game_config;
+mod integration;
+mod model;
+mod camera;
+mod headless;
+mod software;
+mod error;
+mod record;
+mod trace;
+mod save;
+mod scene;
 
-pub use self::graphics::{method_to_parameters, Transforms, Window, WindowArgs, Entity, Vertex, Index, DrawMethod, DepthTestMethod, CullingMethod};
-pub use self::utils::{UNSET, ID, IDManager, IDType, EntityIDType};
+pub use self::graphics::{method_to_parameters, Renderer, Frame, Transforms, Window, WindowArgs, Entity, Vertex, Index, DrawMethod, DepthTestMethod, CullingMethod};
+pub use self::utils::{UNSET, ID, IDManager, IDType, EntityIDType, ENTITY_ID_TYPES};
 pub use self::math::{Mat4, Vec2, Vec3, Vec4};
 pub use self::being::{Being, BeingType};
 pub use self::world::{World, WorldEvent, TickEvent, TickAfterEvent, TransformEvent, EntityGraphicsEvent, EntityIDEvent, Vec2Event, Vec3Event};
 pub use self::game::{Game};
+pub use self::game_config::{GameConfig, DropTimePolicy, MissingBeingPolicy};
+pub use self::integration::{IntegrationMethod};
+pub use self::model::{ModelOrder, compose_model, nest_model};
+pub use self::camera::{Camera, Projection};
 pub use self::keyboard::{Keyboard};
-pub use self::being_args::{BeingArgs};
+pub use self::being_args::{BeingArgs, Property};
+pub use self::headless::{Headless, HeadlessCall};
+pub use self::software::{Software};
+pub use self::error::{PolyclopsError};
+pub use self::save::{NamedBeingType, SavedWorld, SavedBeing, SavedEntity, SAVE_VERSION, world_to_json, world_from_json, save_world_file, load_world_file};
+pub use self::scene::{scene_from_json};
+pub use self::trace::{Tracer, TraceBatch, TraceEntry, TracePhase};
+pub use self::record::{Encode, Decode, Reader, Record, RecordedInput, save_recording, load_recording};
 
 pub fn init() -> IDManager {
     graphics::init_vertex();
@@ -196,6 +196,16 @@ impl Mat4 {
 		other
 	}
 
+	pub fn to_transpose(&self) -> Mat4 {
+		let mut new = Mat4::zero();
+		for x in 0..4 {
+			for y in 0..4 {
+				new[x][y] = self[y][x];
+			}
+		}
+		new
+	}
+
 	fn swap_rows(&mut self, y1: usize, y2: usize) {
 		let row = self[y1];
 		self[y1] = self[y2];
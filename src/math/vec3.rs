@@ -35,6 +35,10 @@ impl Vec3 {
 	pub fn to_vec4(&self, w: f32) -> Vec4 {
 		Vec4::from([self[0], self[1], self[2], w])
 	}
+
+	pub fn lerp(&self, other: Vec3, alpha: f32) -> Vec3 {
+		*self + (other - *self) * alpha
+	}
 }
 
 impl From<[f32; 3]> for Vec3 {
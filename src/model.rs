@@ -0,0 +1,38 @@
+use math::{Vec3, Mat4};
+
+// the order the translation, scalation and rotation matrices are multiplied in, the rightmost applies to the vertices first
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum ModelOrder {
+    TranslationScalationRotation,
+    TranslationRotationScalation,
+    ScalationTranslationRotation,
+    ScalationRotationTranslation,
+    RotationTranslationScalation,
+    RotationScalationTranslation,
+}
+
+// inner's space placed inside outer's, as for a child in its parent
+// Mat4's Mul hands back its product transposed
+pub fn nest_model(outer: Mat4, inner: Mat4) -> Mat4 {
+    (outer * inner).to_transpose()
+}
+
+// the pivot is in the being's local space and is moved to the origin before scaling and rotating
+pub fn compose_model(pos: Vec3, rot: Vec3, sca: Vec3, pivot: Vec3, order: ModelOrder) -> Mat4 {
+    let translation = Mat4::translation_from_vec3(pos);
+    let scalation = Mat4::scalation_from_vec3(sca);
+    let rotation = Mat4::rotation_from_vec3(rot);
+    let model = match order {
+        ModelOrder::TranslationScalationRotation => translation * scalation * rotation,
+        ModelOrder::TranslationRotationScalation => translation * rotation * scalation,
+        ModelOrder::ScalationTranslationRotation => scalation * translation * rotation,
+        ModelOrder::ScalationRotationTranslation => scalation * rotation * translation,
+        ModelOrder::RotationTranslationScalation => rotation * translation * scalation,
+        ModelOrder::RotationScalationTranslation => rotation * scalation * translation,
+    };
+    if pivot == Vec3::zero() {
+        model
+    } else {
+        nest_model(model, Mat4::translation_from_vec3(pivot * -1.0))
+    }
+}
@@ -0,0 +1,1113 @@
+use glium::glutin::ElementState as GliumElementState;
+use glium::glutin::MouseButton as GliumMouseButton;
+use glium::glutin::VirtualKeyCode as GliumKeyCode;
+use std::borrow::{Cow};
+use std::fs::{File};
+use std::io::{Read, Write};
+use std::collections::{HashMap};
+
+use utils::{ID, EntityIDType};
+use math::{Mat4, Vec2, Vec3};
+use graphics::{Vertex, DrawMethod, DepthTestMethod, CullingMethod};
+use camera::{Camera, Projection};
+use world::{WorldEvent, TickEvent, TickAfterEvent, TransformEvent, EntityGraphicsEvent, EntityIDEvent, Vec2Event, Vec3Event};
+use being::{BeingType};
+use being_args::{BeingArgs, Property};
+use error::{PolyclopsError};
+
+const MAGIC: &'static [u8] = b"PCLR";
+const VERSION: u8 = 2;
+
+pub trait Encode {
+    fn encode(&self, out: &mut Vec<u8>);
+}
+
+pub trait Decode: Sized {
+    fn decode(reader: &mut Reader) -> Result<Self, PolyclopsError>;
+}
+
+pub struct Reader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(data: &'a [u8]) -> Reader<'a> {
+        Reader {
+            data: data,
+            position: 0,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.position >= self.data.len()
+    }
+
+    pub fn read_bytes(&mut self, count: usize) -> Result<&'a [u8], PolyclopsError> {
+        if self.position + count > self.data.len() {
+            return Err(PolyclopsError::Decode("Unexpected End of Recording".to_string()));
+        }
+        let bytes = &self.data[self.position..self.position + count];
+        self.position += count;
+        Ok(bytes)
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8, PolyclopsError> {
+        Ok(try!(self.read_bytes(1))[0])
+    }
+}
+
+fn invalid_tag(name: &str, tag: u8) -> PolyclopsError {
+    PolyclopsError::Decode(format!("Invalid {} Tag {}", name, tag))
+}
+
+pub fn save_recording(path: &str, data: &[u8]) -> Result<(), PolyclopsError> {
+    let mut file = try!(File::create(path).map_err(|err| PolyclopsError::Io(format!("{}", err))));
+    file.write_all(data).map_err(|err| PolyclopsError::Io(format!("{}", err)))
+}
+
+pub fn load_recording(path: &str) -> Result<Vec<u8>, PolyclopsError> {
+    let mut file = try!(File::open(path).map_err(|err| PolyclopsError::Io(format!("{}", err))));
+    let mut data = vec!();
+    try!(file.read_to_end(&mut data).map_err(|err| PolyclopsError::Io(format!("{}", err))));
+    Ok(data)
+}
+
+// one entry in a recording, written in the order things happened
+pub enum Record<T: BeingType<T>> {
+    Start(ID, Vec<WorldEvent<T>>),
+    Input(RecordedInput),
+    Tick(ID, f32, Vec<TickEvent<T>>),
+    TickAfter(ID, Vec<TickAfterEvent<T>>),
+    EndStep,
+}
+
+#[derive(Clone)]
+pub enum RecordedInput {
+    Keyboard(GliumKeyCode, GliumElementState),
+    MouseButton(GliumMouseButton, GliumElementState),
+    MousePos(i32, i32),
+    Resolution(u32, u32),
+    Paused(bool),
+    ActiveWorld(ID),
+}
+
+// records are kept as they are and only encoded in into_data, so recording needs no Encode bound on the game
+pub struct Recorder<T: BeingType<T>> {
+    records: Vec<Record<T>>,
+}
+
+impl<T: BeingType<T>> Recorder<T> {
+    pub fn new() -> Recorder<T> {
+        Recorder {
+            records: vec!(),
+        }
+    }
+
+    pub fn record(&mut self, record: Record<T>) {
+        self.records.push(record);
+    }
+}
+
+impl<T: BeingType<T> + Encode> Recorder<T> where T::Event: Encode {
+    pub fn into_data(self) -> Vec<u8> {
+        let mut data = vec!();
+        data.extend_from_slice(MAGIC);
+        data.push(VERSION);
+        for record in self.records.iter() {
+            record.encode(&mut data);
+        }
+        data
+    }
+}
+
+pub fn read_header(reader: &mut Reader) -> Result<(), PolyclopsError> {
+    if try!(reader.read_bytes(MAGIC.len())) != MAGIC {
+        return Err(PolyclopsError::Decode("Not a Recording".to_string()));
+    }
+    let version = try!(reader.read_u8());
+    if version != VERSION {
+        return Err(PolyclopsError::Decode(format!("Unsupported Recording Version {}", version)));
+    }
+    Ok(())
+}
+
+impl<T: BeingType<T> + Encode> Encode for Record<T> where T::Event: Encode {
+    fn encode(&self, out: &mut Vec<u8>) {
+        match *self {
+            Record::Start(world_id, ref events) => {
+                out.push(0);
+                world_id.encode(out);
+                events.encode(out);
+            },
+            Record::Input(ref input) => {
+                out.push(1);
+                input.encode(out);
+            },
+            Record::Tick(world_id, delta_time, ref events) => {
+                out.push(2);
+                world_id.encode(out);
+                delta_time.encode(out);
+                events.encode(out);
+            },
+            Record::TickAfter(world_id, ref events) => {
+                out.push(3);
+                world_id.encode(out);
+                events.encode(out);
+            },
+            Record::EndStep => out.push(4),
+        }
+    }
+}
+
+impl<T: BeingType<T> + Decode> Decode for Record<T> where T::Event: Decode {
+    fn decode(reader: &mut Reader) -> Result<Record<T>, PolyclopsError> {
+        match try!(reader.read_u8()) {
+            0 => Ok(Record::Start(try!(ID::decode(reader)), try!(Vec::decode(reader)))),
+            1 => Ok(Record::Input(try!(RecordedInput::decode(reader)))),
+            2 => Ok(Record::Tick(try!(ID::decode(reader)), try!(f32::decode(reader)), try!(Vec::decode(reader)))),
+            3 => Ok(Record::TickAfter(try!(ID::decode(reader)), try!(Vec::decode(reader)))),
+            4 => Ok(Record::EndStep),
+            tag => Err(invalid_tag("Record", tag)),
+        }
+    }
+}
+
+impl Encode for RecordedInput {
+    fn encode(&self, out: &mut Vec<u8>) {
+        match *self {
+            RecordedInput::Keyboard(key, state) => {
+                out.push(0);
+                key.encode(out);
+                state.encode(out);
+            },
+            RecordedInput::MouseButton(button, state) => {
+                out.push(1);
+                button.encode(out);
+                state.encode(out);
+            },
+            RecordedInput::MousePos(x, y) => {
+                out.push(2);
+                x.encode(out);
+                y.encode(out);
+            },
+            RecordedInput::Resolution(width, height) => {
+                out.push(3);
+                width.encode(out);
+                height.encode(out);
+            },
+            RecordedInput::Paused(paused) => {
+                out.push(4);
+                paused.encode(out);
+            },
+            RecordedInput::ActiveWorld(world_id) => {
+                out.push(5);
+                world_id.encode(out);
+            },
+        }
+    }
+}
+
+impl Decode for RecordedInput {
+    fn decode(reader: &mut Reader) -> Result<RecordedInput, PolyclopsError> {
+        match try!(reader.read_u8()) {
+            0 => Ok(RecordedInput::Keyboard(try!(GliumKeyCode::decode(reader)), try!(GliumElementState::decode(reader)))),
+            1 => Ok(RecordedInput::MouseButton(try!(GliumMouseButton::decode(reader)), try!(GliumElementState::decode(reader)))),
+            2 => Ok(RecordedInput::MousePos(try!(i32::decode(reader)), try!(i32::decode(reader)))),
+            3 => Ok(RecordedInput::Resolution(try!(u32::decode(reader)), try!(u32::decode(reader)))),
+            4 => Ok(RecordedInput::Paused(try!(bool::decode(reader)))),
+            5 => Ok(RecordedInput::ActiveWorld(try!(ID::decode(reader)))),
+            tag => Err(invalid_tag("Input", tag)),
+        }
+    }
+}
+
+impl Encode for u8 {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.push(*self);
+    }
+}
+
+impl Decode for u8 {
+    fn decode(reader: &mut Reader) -> Result<u8, PolyclopsError> {
+        reader.read_u8()
+    }
+}
+
+impl Encode for u32 {
+    fn encode(&self, out: &mut Vec<u8>) {
+        for shift in 0..4 {
+            out.push((*self >> (shift * 8)) as u8);
+        }
+    }
+}
+
+impl Decode for u32 {
+    fn decode(reader: &mut Reader) -> Result<u32, PolyclopsError> {
+        let bytes = try!(reader.read_bytes(4));
+        let mut value = 0;
+        for shift in 0..4 {
+            value |= (bytes[shift] as u32) << (shift * 8);
+        }
+        Ok(value)
+    }
+}
+
+impl Encode for i32 {
+    fn encode(&self, out: &mut Vec<u8>) {
+        (*self as u32).encode(out);
+    }
+}
+
+impl Decode for i32 {
+    fn decode(reader: &mut Reader) -> Result<i32, PolyclopsError> {
+        Ok(try!(u32::decode(reader)) as i32)
+    }
+}
+
+impl Encode for f32 {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.to_bits().encode(out);
+    }
+}
+
+impl Decode for f32 {
+    fn decode(reader: &mut Reader) -> Result<f32, PolyclopsError> {
+        Ok(f32::from_bits(try!(u32::decode(reader))))
+    }
+}
+
+impl Encode for bool {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.push(if *self { 1 } else { 0 });
+    }
+}
+
+impl Decode for bool {
+    fn decode(reader: &mut Reader) -> Result<bool, PolyclopsError> {
+        Ok(try!(reader.read_u8()) != 0)
+    }
+}
+
+impl Encode for () {
+    fn encode(&self, _: &mut Vec<u8>) {
+
+    }
+}
+
+impl Decode for () {
+    fn decode(_: &mut Reader) -> Result<(), PolyclopsError> {
+        Ok(())
+    }
+}
+
+impl<E: Encode> Encode for Vec<E> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        (self.len() as u32).encode(out);
+        for item in self {
+            item.encode(out);
+        }
+    }
+}
+
+impl<E: Decode> Decode for Vec<E> {
+    fn decode(reader: &mut Reader) -> Result<Vec<E>, PolyclopsError> {
+        let len = try!(u32::decode(reader));
+        let mut items = vec!();
+        for _ in 0..len {
+            items.push(try!(E::decode(reader)));
+        }
+        Ok(items)
+    }
+}
+
+impl<E: Encode> Encode for Option<E> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        match *self {
+            Some(ref item) => {
+                out.push(1);
+                item.encode(out);
+            },
+            None => out.push(0),
+        }
+    }
+}
+
+impl<E: Decode> Decode for Option<E> {
+    fn decode(reader: &mut Reader) -> Result<Option<E>, PolyclopsError> {
+        match try!(reader.read_u8()) {
+            0 => Ok(None),
+            _ => Ok(Some(try!(E::decode(reader)))),
+        }
+    }
+}
+
+impl<E: Encode> Encode for Box<E> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        (**self).encode(out);
+    }
+}
+
+impl<E: Decode> Decode for Box<E> {
+    fn decode(reader: &mut Reader) -> Result<Box<E>, PolyclopsError> {
+        Ok(Box::new(try!(E::decode(reader))))
+    }
+}
+
+impl Encode for String {
+    fn encode(&self, out: &mut Vec<u8>) {
+        (self.len() as u32).encode(out);
+        out.extend_from_slice(self.as_bytes());
+    }
+}
+
+impl Decode for String {
+    fn decode(reader: &mut Reader) -> Result<String, PolyclopsError> {
+        let len = try!(u32::decode(reader));
+        let bytes = try!(reader.read_bytes(len as usize));
+        String::from_utf8(bytes.to_vec()).map_err(|err| PolyclopsError::Decode(format!("{}", err)))
+    }
+}
+
+// entries are written in key order so equal maps always encode the same
+impl<E: Encode> Encode for HashMap<String, E> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        let mut keys: Vec<&String> = self.keys().collect();
+        keys.sort();
+        (keys.len() as u32).encode(out);
+        for key in keys {
+            key.encode(out);
+            self[key].encode(out);
+        }
+    }
+}
+
+impl<E: Decode> Decode for HashMap<String, E> {
+    fn decode(reader: &mut Reader) -> Result<HashMap<String, E>, PolyclopsError> {
+        let len = try!(u32::decode(reader));
+        let mut map = HashMap::new();
+        for _ in 0..len {
+            let key = try!(String::decode(reader));
+            map.insert(key, try!(E::decode(reader)));
+        }
+        Ok(map)
+    }
+}
+
+impl Encode for Vec2 {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self[0].encode(out);
+        self[1].encode(out);
+    }
+}
+
+impl Decode for Vec2 {
+    fn decode(reader: &mut Reader) -> Result<Vec2, PolyclopsError> {
+        Ok(Vec2::from([try!(f32::decode(reader)), try!(f32::decode(reader))]))
+    }
+}
+
+impl Encode for Vec3 {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self[0].encode(out);
+        self[1].encode(out);
+        self[2].encode(out);
+    }
+}
+
+impl Decode for Vec3 {
+    fn decode(reader: &mut Reader) -> Result<Vec3, PolyclopsError> {
+        Ok(Vec3::from([try!(f32::decode(reader)), try!(f32::decode(reader)), try!(f32::decode(reader))]))
+    }
+}
+
+impl Encode for Mat4 {
+    fn encode(&self, out: &mut Vec<u8>) {
+        for column in 0..4 {
+            for val in self[column].get_vals().iter() {
+                val.encode(out);
+            }
+        }
+    }
+}
+
+impl Decode for Mat4 {
+    fn decode(reader: &mut Reader) -> Result<Mat4, PolyclopsError> {
+        let mut vals = [[0.0; 4]; 4];
+        for column in 0..4 {
+            for row in 0..4 {
+                vals[column][row] = try!(f32::decode(reader));
+            }
+        }
+        Ok(Mat4::from(vals))
+    }
+}
+
+impl Encode for Vertex {
+    fn encode(&self, out: &mut Vec<u8>) {
+        for val in self.get_position().iter() {
+            val.encode(out);
+        }
+        for val in self.get_tex_coord().iter() {
+            val.encode(out);
+        }
+    }
+}
+
+impl Decode for Vertex {
+    fn decode(reader: &mut Reader) -> Result<Vertex, PolyclopsError> {
+        let position = [try!(f32::decode(reader)), try!(f32::decode(reader)), try!(f32::decode(reader))];
+        let tex_coord = [try!(f32::decode(reader)), try!(f32::decode(reader))];
+        Ok(Vertex::new(position, tex_coord))
+    }
+}
+
+impl Encode for Projection {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.push(match *self {
+            Projection::Orthographic => 0,
+            Projection::Perspective => 1,
+        });
+    }
+}
+
+impl Decode for Projection {
+    fn decode(reader: &mut Reader) -> Result<Projection, PolyclopsError> {
+        match try!(reader.read_u8()) {
+            0 => Ok(Projection::Orthographic),
+            1 => Ok(Projection::Perspective),
+            tag => Err(invalid_tag("Projection", tag)),
+        }
+    }
+}
+
+impl Encode for Camera {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.projection.encode(out);
+        self.pos.encode(out);
+        self.pitch.encode(out);
+        self.yaw.encode(out);
+        self.zoom.encode(out);
+        self.near.encode(out);
+        self.far.encode(out);
+        self.field_of_view.encode(out);
+        self.aspect_ratio.encode(out);
+    }
+}
+
+impl Decode for Camera {
+    fn decode(reader: &mut Reader) -> Result<Camera, PolyclopsError> {
+        Ok(Camera {
+            projection: try!(Projection::decode(reader)),
+            pos: try!(Vec3::decode(reader)),
+            pitch: try!(f32::decode(reader)),
+            yaw: try!(f32::decode(reader)),
+            zoom: try!(f32::decode(reader)),
+            near: try!(f32::decode(reader)),
+            far: try!(f32::decode(reader)),
+            field_of_view: try!(f32::decode(reader)),
+            aspect_ratio: try!(f32::decode(reader)),
+        })
+    }
+}
+
+impl Encode for BeingArgs {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.pos.encode(out);
+        self.vel.encode(out);
+        self.acc.encode(out);
+        self.sca.encode(out);
+        self.rot.encode(out);
+        self.name.encode(out);
+        self.tags.encode(out);
+        self.texture.encode(out);
+        self.properties.encode(out);
+    }
+}
+
+impl Decode for BeingArgs {
+    fn decode(reader: &mut Reader) -> Result<BeingArgs, PolyclopsError> {
+        let mut being_args = BeingArgs::new();
+        being_args.pos = try!(Option::decode(reader));
+        being_args.vel = try!(Option::decode(reader));
+        being_args.acc = try!(Option::decode(reader));
+        being_args.sca = try!(Option::decode(reader));
+        being_args.rot = try!(Option::decode(reader));
+        being_args.name = try!(Option::decode(reader));
+        being_args.tags = try!(Vec::decode(reader));
+        being_args.texture = try!(Option::decode(reader));
+        being_args.properties = try!(HashMap::decode(reader));
+        Ok(being_args)
+    }
+}
+
+impl Encode for Property {
+    fn encode(&self, out: &mut Vec<u8>) {
+        match *self {
+            Property::Bool(value) => {
+                out.push(0);
+                value.encode(out);
+            },
+            Property::Int(value) => {
+                out.push(1);
+                value.encode(out);
+            },
+            Property::Float(value) => {
+                out.push(2);
+                value.encode(out);
+            },
+            Property::Text(ref value) => {
+                out.push(3);
+                value.encode(out);
+            },
+            Property::Vec3(value) => {
+                out.push(4);
+                value.encode(out);
+            },
+        }
+    }
+}
+
+impl Decode for Property {
+    fn decode(reader: &mut Reader) -> Result<Property, PolyclopsError> {
+        match try!(reader.read_u8()) {
+            0 => Ok(Property::Bool(try!(bool::decode(reader)))),
+            1 => Ok(Property::Int(try!(i32::decode(reader)))),
+            2 => Ok(Property::Float(try!(f32::decode(reader)))),
+            3 => Ok(Property::Text(try!(String::decode(reader)))),
+            4 => Ok(Property::Vec3(try!(Vec3::decode(reader)))),
+            tag => Err(invalid_tag("Property", tag)),
+        }
+    }
+}
+
+impl Encode for EntityIDType {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.push(match *self {
+            EntityIDType::Vertex => 0,
+            EntityIDType::Index => 1,
+            EntityIDType::Texture => 2,
+            EntityIDType::DrawParameter => 3,
+            EntityIDType::Perspective => 4,
+            EntityIDType::View => 5,
+            EntityIDType::Model => 6,
+        });
+    }
+}
+
+impl Decode for EntityIDType {
+    fn decode(reader: &mut Reader) -> Result<EntityIDType, PolyclopsError> {
+        match try!(reader.read_u8()) {
+            0 => Ok(EntityIDType::Vertex),
+            1 => Ok(EntityIDType::Index),
+            2 => Ok(EntityIDType::Texture),
+            3 => Ok(EntityIDType::DrawParameter),
+            4 => Ok(EntityIDType::Perspective),
+            5 => Ok(EntityIDType::View),
+            6 => Ok(EntityIDType::Model),
+            tag => Err(invalid_tag("Entity ID Type", tag)),
+        }
+    }
+}
+
+impl Encode for DrawMethod {
+    fn encode(&self, out: &mut Vec<u8>) {
+        match *self {
+            DrawMethod::Both(ref depth, ref cull) => {
+                out.push(0);
+                depth.encode(out);
+                cull.encode(out);
+            },
+            DrawMethod::Depth(ref depth) => {
+                out.push(1);
+                depth.encode(out);
+            },
+            DrawMethod::Culling(ref cull) => {
+                out.push(2);
+                cull.encode(out);
+            },
+            DrawMethod::Neither => out.push(3),
+        }
+    }
+}
+
+impl Decode for DrawMethod {
+    fn decode(reader: &mut Reader) -> Result<DrawMethod, PolyclopsError> {
+        match try!(reader.read_u8()) {
+            0 => Ok(DrawMethod::Both(try!(DepthTestMethod::decode(reader)), try!(CullingMethod::decode(reader)))),
+            1 => Ok(DrawMethod::Depth(try!(DepthTestMethod::decode(reader)))),
+            2 => Ok(DrawMethod::Culling(try!(CullingMethod::decode(reader)))),
+            3 => Ok(DrawMethod::Neither),
+            tag => Err(invalid_tag("Draw Method", tag)),
+        }
+    }
+}
+
+impl Encode for DepthTestMethod {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.push(match *self {
+            DepthTestMethod::IfLess => 0,
+        });
+    }
+}
+
+impl Decode for DepthTestMethod {
+    fn decode(reader: &mut Reader) -> Result<DepthTestMethod, PolyclopsError> {
+        match try!(reader.read_u8()) {
+            0 => Ok(DepthTestMethod::IfLess),
+            tag => Err(invalid_tag("Depth Test Method", tag)),
+        }
+    }
+}
+
+impl Encode for CullingMethod {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.push(match *self {
+            CullingMethod::Clockwise => 0,
+            CullingMethod::CounterClockwise => 1,
+        });
+    }
+}
+
+impl Decode for CullingMethod {
+    fn decode(reader: &mut Reader) -> Result<CullingMethod, PolyclopsError> {
+        match try!(reader.read_u8()) {
+            0 => Ok(CullingMethod::Clockwise),
+            1 => Ok(CullingMethod::CounterClockwise),
+            tag => Err(invalid_tag("Culling Method", tag)),
+        }
+    }
+}
+
+impl<T: BeingType<T> + Encode> Encode for WorldEvent<T> where T::Event: Encode {
+    fn encode(&self, out: &mut Vec<u8>) {
+        match *self {
+            WorldEvent::Tick(ref event) => {
+                out.push(0);
+                event.encode(out);
+            },
+            WorldEvent::TickAfter(ref event) => {
+                out.push(1);
+                event.encode(out);
+            },
+        }
+    }
+}
+
+impl<T: BeingType<T> + Decode> Decode for WorldEvent<T> where T::Event: Decode {
+    fn decode(reader: &mut Reader) -> Result<WorldEvent<T>, PolyclopsError> {
+        match try!(reader.read_u8()) {
+            0 => Ok(WorldEvent::Tick(try!(TickEvent::decode(reader)))),
+            1 => Ok(WorldEvent::TickAfter(try!(TickAfterEvent::decode(reader)))),
+            tag => Err(invalid_tag("World Event", tag)),
+        }
+    }
+}
+
+impl<T: BeingType<T> + Encode> Encode for TickAfterEvent<T> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        match *self {
+            TickAfterEvent::Entity(being_id, entity_id, ref event) => {
+                out.push(0);
+                being_id.encode(out);
+                entity_id.encode(out);
+                event.encode(out);
+            },
+            TickAfterEvent::EntityBase(ref being_type, entity_id, ref event) => {
+                out.push(1);
+                being_type.encode(out);
+                entity_id.encode(out);
+                event.encode(out);
+            },
+            TickAfterEvent::EndBeing(being_id) => {
+                out.push(2);
+                being_id.encode(out);
+            },
+        }
+    }
+}
+
+impl<T: BeingType<T> + Decode> Decode for TickAfterEvent<T> {
+    fn decode(reader: &mut Reader) -> Result<TickAfterEvent<T>, PolyclopsError> {
+        match try!(reader.read_u8()) {
+            0 => Ok(TickAfterEvent::Entity(try!(ID::decode(reader)), try!(u32::decode(reader)), try!(EntityGraphicsEvent::decode(reader)))),
+            1 => Ok(TickAfterEvent::EntityBase(try!(T::decode(reader)), try!(u32::decode(reader)), try!(EntityGraphicsEvent::decode(reader)))),
+            2 => Ok(TickAfterEvent::EndBeing(try!(ID::decode(reader)))),
+            tag => Err(invalid_tag("Tick After Event", tag)),
+        }
+    }
+}
+
+impl<T: BeingType<T> + Encode> Encode for TickEvent<T> where T::Event: Encode {
+    fn encode(&self, out: &mut Vec<u8>) {
+        match *self {
+            TickEvent::NewBeing(ref being_type, ref being_args) => {
+                out.push(0);
+                being_type.encode(out);
+                being_args.encode(out);
+            },
+            TickEvent::NewBase(ref being_type) => {
+                out.push(1);
+                being_type.encode(out);
+            },
+            TickEvent::Sca2(id, ref event) => encode_id_event(out, 2, id, event),
+            TickEvent::Sca3(id, ref event) => encode_id_event(out, 3, id, event),
+            TickEvent::Rot2(id, ref event) => encode_id_event(out, 4, id, event),
+            TickEvent::Rot3(id, ref event) => encode_id_event(out, 5, id, event),
+            TickEvent::Pos2(id, ref event) => encode_id_event(out, 6, id, event),
+            TickEvent::Pos3(id, ref event) => encode_id_event(out, 7, id, event),
+            TickEvent::Vel2(id, ref event) => encode_id_event(out, 8, id, event),
+            TickEvent::Vel3(id, ref event) => encode_id_event(out, 9, id, event),
+            TickEvent::Acc2(id, ref event) => encode_id_event(out, 10, id, event),
+            TickEvent::Acc3(id, ref event) => encode_id_event(out, 11, id, event),
+            TickEvent::EntityID(being_id, entity_id, ref event) => {
+                out.push(12);
+                being_id.encode(out);
+                entity_id.encode(out);
+                event.encode(out);
+            },
+            TickEvent::EntityIDBase(ref being_type, entity_id, ref event) => {
+                out.push(13);
+                being_type.encode(out);
+                entity_id.encode(out);
+                event.encode(out);
+            },
+            TickEvent::Transform(being_id, entity_id, ref event) => {
+                out.push(14);
+                being_id.encode(out);
+                entity_id.encode(out);
+                event.encode(out);
+            },
+            TickEvent::TransformBase(ref being_type, entity_id, ref event) => {
+                out.push(15);
+                being_type.encode(out);
+                entity_id.encode(out);
+                event.encode(out);
+            },
+            TickEvent::SetPaused(paused) => {
+                out.push(16);
+                paused.encode(out);
+            },
+            TickEvent::SetActiveWorld(world_id) => {
+                out.push(17);
+                world_id.encode(out);
+            },
+            TickEvent::Message(target, ref message) => {
+                out.push(18);
+                target.encode(out);
+                message.encode(out);
+            },
+            TickEvent::Broadcast(ref being_type, ref message) => {
+                out.push(19);
+                being_type.encode(out);
+                message.encode(out);
+            },
+            TickEvent::Custom(ref event) => {
+                out.push(20);
+                event.encode(out);
+            },
+            TickEvent::Attach(child, parent) => {
+                out.push(21);
+                child.encode(out);
+                parent.encode(out);
+            },
+            TickEvent::Detach(child) => {
+                out.push(22);
+                child.encode(out);
+            },
+            TickEvent::AttachEntity(being_id, child, parent) => {
+                out.push(23);
+                being_id.encode(out);
+                child.encode(out);
+                parent.encode(out);
+            },
+            TickEvent::DetachEntity(being_id, child) => {
+                out.push(24);
+                being_id.encode(out);
+                child.encode(out);
+            },
+            TickEvent::SetCamera(ref camera) => {
+                out.push(25);
+                camera.encode(out);
+            },
+        }
+    }
+}
+
+fn encode_id_event<E: Encode>(out: &mut Vec<u8>, tag: u8, id: ID, event: &E) {
+    out.push(tag);
+    id.encode(out);
+    event.encode(out);
+}
+
+impl<T: BeingType<T> + Decode> Decode for TickEvent<T> where T::Event: Decode {
+    fn decode(reader: &mut Reader) -> Result<TickEvent<T>, PolyclopsError> {
+        match try!(reader.read_u8()) {
+            0 => Ok(TickEvent::NewBeing(try!(T::decode(reader)), try!(BeingArgs::decode(reader)))),
+            1 => Ok(TickEvent::NewBase(try!(T::decode(reader)))),
+            2 => Ok(TickEvent::Sca2(try!(ID::decode(reader)), try!(Vec2Event::decode(reader)))),
+            3 => Ok(TickEvent::Sca3(try!(ID::decode(reader)), try!(Vec3Event::decode(reader)))),
+            4 => Ok(TickEvent::Rot2(try!(ID::decode(reader)), try!(Vec2Event::decode(reader)))),
+            5 => Ok(TickEvent::Rot3(try!(ID::decode(reader)), try!(Vec3Event::decode(reader)))),
+            6 => Ok(TickEvent::Pos2(try!(ID::decode(reader)), try!(Vec2Event::decode(reader)))),
+            7 => Ok(TickEvent::Pos3(try!(ID::decode(reader)), try!(Vec3Event::decode(reader)))),
+            8 => Ok(TickEvent::Vel2(try!(ID::decode(reader)), try!(Vec2Event::decode(reader)))),
+            9 => Ok(TickEvent::Vel3(try!(ID::decode(reader)), try!(Vec3Event::decode(reader)))),
+            10 => Ok(TickEvent::Acc2(try!(ID::decode(reader)), try!(Vec2Event::decode(reader)))),
+            11 => Ok(TickEvent::Acc3(try!(ID::decode(reader)), try!(Vec3Event::decode(reader)))),
+            12 => Ok(TickEvent::EntityID(try!(ID::decode(reader)), try!(u32::decode(reader)), try!(EntityIDEvent::decode(reader)))),
+            13 => Ok(TickEvent::EntityIDBase(try!(T::decode(reader)), try!(u32::decode(reader)), try!(EntityIDEvent::decode(reader)))),
+            14 => Ok(TickEvent::Transform(try!(ID::decode(reader)), try!(u32::decode(reader)), try!(TransformEvent::decode(reader)))),
+            15 => Ok(TickEvent::TransformBase(try!(T::decode(reader)), try!(u32::decode(reader)), try!(TransformEvent::decode(reader)))),
+            16 => Ok(TickEvent::SetPaused(try!(bool::decode(reader)))),
+            17 => Ok(TickEvent::SetActiveWorld(try!(ID::decode(reader)))),
+            18 => Ok(TickEvent::Message(try!(ID::decode(reader)), try!(T::Event::decode(reader)))),
+            19 => Ok(TickEvent::Broadcast(try!(T::decode(reader)), try!(T::Event::decode(reader)))),
+            20 => Ok(TickEvent::Custom(try!(T::Event::decode(reader)))),
+            21 => Ok(TickEvent::Attach(try!(ID::decode(reader)), try!(ID::decode(reader)))),
+            22 => Ok(TickEvent::Detach(try!(ID::decode(reader)))),
+            23 => Ok(TickEvent::AttachEntity(try!(ID::decode(reader)), try!(u32::decode(reader)), try!(u32::decode(reader)))),
+            24 => Ok(TickEvent::DetachEntity(try!(ID::decode(reader)), try!(u32::decode(reader)))),
+            25 => Ok(TickEvent::SetCamera(try!(Camera::decode(reader)))),
+            tag => Err(invalid_tag("Tick Event", tag)),
+        }
+    }
+}
+
+impl Encode for TransformEvent {
+    fn encode(&self, out: &mut Vec<u8>) {
+        let (tag, matrix, inverse) = match *self {
+            TransformEvent::Perspective(matrix, inverse) => (0, matrix, inverse),
+            TransformEvent::View(matrix, inverse) => (1, matrix, inverse),
+            TransformEvent::Model(matrix, inverse) => (2, matrix, inverse),
+        };
+        out.push(tag);
+        matrix.encode(out);
+        inverse.encode(out);
+    }
+}
+
+impl Decode for TransformEvent {
+    fn decode(reader: &mut Reader) -> Result<TransformEvent, PolyclopsError> {
+        let tag = try!(reader.read_u8());
+        let matrix = try!(Mat4::decode(reader));
+        let inverse = try!(Mat4::decode(reader));
+        match tag {
+            0 => Ok(TransformEvent::Perspective(matrix, inverse)),
+            1 => Ok(TransformEvent::View(matrix, inverse)),
+            2 => Ok(TransformEvent::Model(matrix, inverse)),
+            tag => Err(invalid_tag("Transform Event", tag)),
+        }
+    }
+}
+
+impl<T: BeingType<T> + Encode> Encode for EntityIDEvent<T> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        match *self {
+            EntityIDEvent::UseNewID(ref ids) => {
+                out.push(0);
+                ids.encode(out);
+            },
+            EntityIDEvent::UseOldID(being_id, entity_id, ref ids) => {
+                out.push(1);
+                being_id.encode(out);
+                entity_id.encode(out);
+                ids.encode(out);
+            },
+            EntityIDEvent::UseBaseID(ref being_type, entity_id, ref ids) => {
+                out.push(2);
+                being_type.encode(out);
+                entity_id.encode(out);
+                ids.encode(out);
+            },
+        }
+    }
+}
+
+impl<T: BeingType<T> + Decode> Decode for EntityIDEvent<T> {
+    fn decode(reader: &mut Reader) -> Result<EntityIDEvent<T>, PolyclopsError> {
+        match try!(reader.read_u8()) {
+            0 => Ok(EntityIDEvent::UseNewID(try!(Vec::decode(reader)))),
+            1 => Ok(EntityIDEvent::UseOldID(try!(ID::decode(reader)), try!(u32::decode(reader)), try!(Vec::decode(reader)))),
+            2 => Ok(EntityIDEvent::UseBaseID(try!(T::decode(reader)), try!(u32::decode(reader)), try!(Vec::decode(reader)))),
+            tag => Err(invalid_tag("Entity ID Event", tag)),
+        }
+    }
+}
+
+impl Encode for EntityGraphicsEvent {
+    fn encode(&self, out: &mut Vec<u8>) {
+        match *self {
+            EntityGraphicsEvent::Vertices(ref vertices) => {
+                out.push(0);
+                vertices.encode(out);
+            },
+            EntityGraphicsEvent::Indices(ref indices) => {
+                out.push(1);
+                indices.encode(out);
+            },
+            EntityGraphicsEvent::Texture(ref texture) => {
+                out.push(2);
+                texture.to_vec().encode(out);
+            },
+            EntityGraphicsEvent::DrawMethod(ref draw_method) => {
+                out.push(3);
+                draw_method.encode(out);
+            },
+            EntityGraphicsEvent::UseCamera(camera) => {
+                out.push(4);
+                camera.encode(out);
+            },
+        }
+    }
+}
+
+impl Decode for EntityGraphicsEvent {
+    fn decode(reader: &mut Reader) -> Result<EntityGraphicsEvent, PolyclopsError> {
+        match try!(reader.read_u8()) {
+            0 => Ok(EntityGraphicsEvent::Vertices(try!(Vec::decode(reader)))),
+            1 => Ok(EntityGraphicsEvent::Indices(try!(Vec::decode(reader)))),
+            2 => {
+                let texture: Vec<u8> = try!(Vec::decode(reader));
+                Ok(EntityGraphicsEvent::Texture(Cow::Owned(texture)))
+            },
+            3 => Ok(EntityGraphicsEvent::DrawMethod(try!(DrawMethod::decode(reader)))),
+            4 => Ok(EntityGraphicsEvent::UseCamera(try!(bool::decode(reader)))),
+            tag => Err(invalid_tag("Entity Graphics Event", tag)),
+        }
+    }
+}
+
+impl Encode for Vec2Event {
+    fn encode(&self, out: &mut Vec<u8>) {
+        let (tag, vec2) = match *self {
+            Vec2Event::Set(vec2) => (0, vec2),
+            Vec2Event::Add(vec2) => (1, vec2),
+            Vec2Event::Mul(vec2) => (2, vec2),
+        };
+        out.push(tag);
+        vec2.encode(out);
+    }
+}
+
+impl Decode for Vec2Event {
+    fn decode(reader: &mut Reader) -> Result<Vec2Event, PolyclopsError> {
+        let tag = try!(reader.read_u8());
+        let vec2 = try!(Vec2::decode(reader));
+        match tag {
+            0 => Ok(Vec2Event::Set(vec2)),
+            1 => Ok(Vec2Event::Add(vec2)),
+            2 => Ok(Vec2Event::Mul(vec2)),
+            tag => Err(invalid_tag("Vec2 Event", tag)),
+        }
+    }
+}
+
+impl Encode for Vec3Event {
+    fn encode(&self, out: &mut Vec<u8>) {
+        let (tag, vec3) = match *self {
+            Vec3Event::Set(vec3) => (0, vec3),
+            Vec3Event::Add(vec3) => (1, vec3),
+            Vec3Event::Mul(vec3) => (2, vec3),
+        };
+        out.push(tag);
+        vec3.encode(out);
+    }
+}
+
+impl Decode for Vec3Event {
+    fn decode(reader: &mut Reader) -> Result<Vec3Event, PolyclopsError> {
+        let tag = try!(reader.read_u8());
+        let vec3 = try!(Vec3::decode(reader));
+        match tag {
+            0 => Ok(Vec3Event::Set(vec3)),
+            1 => Ok(Vec3Event::Add(vec3)),
+            2 => Ok(Vec3Event::Mul(vec3)),
+            tag => Err(invalid_tag("Vec3 Event", tag)),
+        }
+    }
+}
+
+impl Encode for GliumElementState {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.push(match *self {
+            GliumElementState::Pressed => 0,
+            GliumElementState::Released => 1,
+        });
+    }
+}
+
+impl Decode for GliumElementState {
+    fn decode(reader: &mut Reader) -> Result<GliumElementState, PolyclopsError> {
+        match try!(reader.read_u8()) {
+            0 => Ok(GliumElementState::Pressed),
+            1 => Ok(GliumElementState::Released),
+            tag => Err(invalid_tag("Element State", tag)),
+        }
+    }
+}
+
+impl Encode for GliumMouseButton {
+    fn encode(&self, out: &mut Vec<u8>) {
+        match *self {
+            GliumMouseButton::Left => out.push(0),
+            GliumMouseButton::Right => out.push(1),
+            GliumMouseButton::Middle => out.push(2),
+            GliumMouseButton::Other(button) => {
+                out.push(3);
+                button.encode(out);
+            },
+        }
+    }
+}
+
+impl Decode for GliumMouseButton {
+    fn decode(reader: &mut Reader) -> Result<GliumMouseButton, PolyclopsError> {
+        match try!(reader.read_u8()) {
+            0 => Ok(GliumMouseButton::Left),
+            1 => Ok(GliumMouseButton::Right),
+            2 => Ok(GliumMouseButton::Middle),
+            3 => Ok(GliumMouseButton::Other(try!(u8::decode(reader)))),
+            tag => Err(invalid_tag("Mouse Button", tag)),
+        }
+    }
+}
+
+macro_rules! key_codes {
+    ($($key:ident),*) => (
+        const KEY_CODES: &'static [GliumKeyCode] = &[$(GliumKeyCode::$key),*];
+    );
+}
+
+key_codes!(
+    Key1, Key2, Key3, Key4, Key5, Key6, Key7, Key8, Key9, Key0,
+    A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y, Z,
+    Escape,
+    F1, F2, F3, F4, F5, F6, F7, F8, F9, F10, F11, F12, F13, F14, F15,
+    Snapshot, Scroll, Pause,
+    Insert, Home, Delete, End, PageDown, PageUp,
+    Left, Up, Right, Down,
+    Back, Return, Space,
+    Numlock, Numpad0, Numpad1, Numpad2, Numpad3, Numpad4, Numpad5, Numpad6, Numpad7, Numpad8, Numpad9,
+    AbntC1, AbntC2, Add, Apostrophe, Apps, At, Ax, Backslash, Calculator, Capital, Colon, Comma, Convert,
+    Decimal, Divide, Equals, Grave, Kana, Kanji, LAlt, LBracket, LControl, LMenu, LShift, LWin, Mail,
+    MediaSelect, MediaStop, Minus, Multiply, Mute, MyComputer, NextTrack, NoConvert, NumpadComma,
+    NumpadEnter, NumpadEquals, OEM102, Period, PlayPause, Power, PrevTrack, RAlt, RBracket, RControl,
+    RMenu, RShift, RWin, Semicolon, Slash, Sleep, Stop, Subtract, Sysrq, Tab, Underline, Unlabeled,
+    VolumeDown, VolumeUp, Wake, WebBack, WebFavorites, WebForward, WebHome, WebRefresh, WebSearch,
+    WebStop, Yen
+);
+
+impl Encode for GliumKeyCode {
+    fn encode(&self, out: &mut Vec<u8>) {
+        let index = KEY_CODES.iter().position(|key| key == self).expect("Unable to Find Key Code in Encode Key Code");
+        out.push(index as u8);
+    }
+}
+
+impl Decode for GliumKeyCode {
+    fn decode(reader: &mut Reader) -> Result<GliumKeyCode, PolyclopsError> {
+        let index = try!(reader.read_u8());
+        KEY_CODES.get(index as usize).cloned().ok_or(invalid_tag("Key Code", index))
+    }
+}
@@ -0,0 +1,460 @@
+use std::collections::{BTreeMap};
+use std::fs::{File};
+use std::io::{Read, Write};
+use rustc_serialize::json::{Json};
+
+use utils::{EntityIDType, ENTITY_ID_TYPES};
+use math::{Vec3, Mat4};
+use world::{World, TransformEvent};
+use graphics::{Transforms, Entity};
+use being::{BeingType};
+use being_args::{BeingArgs, Property};
+use camera::{Camera, Projection};
+use error::{PolyclopsError};
+
+pub const SAVE_VERSION: u64 = 3;
+
+// stable names for being types, used wherever they are written to or read from text
+pub trait NamedBeingType: Sized {
+    fn get_name(&self) -> &'static str;
+    fn from_name(name: &str) -> Option<Self>;
+}
+
+pub struct SavedWorld<T: BeingType<T>> {
+    pub paused: bool,
+    pub tick_in_background: bool,
+    // the aspect ratio follows the window so it is not saved
+    pub camera: Camera,
+    pub bases: Vec<T>,
+    pub beings: Vec<SavedBeing<T>>,
+}
+
+pub struct SavedBeing<T: BeingType<T>> {
+    pub being_type: T,
+    pub being_args: BeingArgs,
+    pub entities: Vec<SavedEntity>,
+    // index of the parent in SavedWorld::beings
+    pub parent: Option<usize>,
+}
+
+// which of an entity's IDs point at its base's buffers and which are its own, plus its own transforms
+pub struct SavedEntity {
+    pub id: u32,
+    pub shared: Vec<EntityIDType>,
+    pub own: Vec<EntityIDType>,
+    pub transforms: Vec<TransformEvent>,
+    pub parent: Option<u32>,
+    pub camera: bool,
+}
+
+pub fn save_world_file(path: &str, data: &str) -> Result<(), PolyclopsError> {
+    let mut file = try!(File::create(path).map_err(|err| PolyclopsError::Io(format!("{}", err))));
+    file.write_all(data.as_bytes()).map_err(|err| PolyclopsError::Io(format!("{}", err)))
+}
+
+pub fn load_world_file(path: &str) -> Result<String, PolyclopsError> {
+    let mut file = try!(File::open(path).map_err(|err| PolyclopsError::Io(format!("{}", err))));
+    let mut data = String::new();
+    try!(file.read_to_string(&mut data).map_err(|err| PolyclopsError::Io(format!("{}", err))));
+    Ok(data)
+}
+
+// vertices, indices and textures live in the renderer, only which buffers an entity uses is saved
+pub fn world_to_json<T: BeingType<T> + NamedBeingType>(world: &World<T>, transforms: &Transforms) -> String {
+    let mut root = BTreeMap::new();
+    root.insert("version".to_string(), Json::U64(SAVE_VERSION));
+    root.insert("paused".to_string(), Json::Boolean(world.is_paused()));
+    root.insert("tick_in_background".to_string(), Json::Boolean(world.is_ticking_in_background()));
+    root.insert("camera".to_string(), camera_to_json(world.get_camera()));
+    let mut bases: Vec<&'static str> = world.get_bases().keys().map(|being_type| being_type.get_name()).collect();
+    bases.sort();
+    root.insert("bases".to_string(), Json::Array(bases.iter().map(|name| Json::String(name.to_string())).collect()));
+    let mut ids: Vec<_> = world.get_beings().keys().cloned().collect();
+    ids.sort();
+    let mut beings = vec!();
+    for id in ids.iter().cloned() {
+        let being = world.get_being(id).expect("Unable to Get Being in World To Json").read().expect("Unable to Read Being in World To Json");
+        let being_type = being.get_type();
+        let mut object = BTreeMap::new();
+        match world.get_parent(id).and_then(|parent| ids.iter().position(|other| *other == parent)) {
+            Some(parent) => {
+                object.insert("parent".to_string(), Json::U64(parent as u64));
+            },
+            None => (),
+        }
+        object.insert("type".to_string(), Json::String(being_type.get_name().to_string()));
+        object.insert("pos".to_string(), vec3_to_json(being.get_pos3()));
+        object.insert("vel".to_string(), vec3_to_json(being.get_vel3()));
+        object.insert("acc".to_string(), vec3_to_json(being.get_acc3()));
+        object.insert("sca".to_string(), vec3_to_json(being.get_sca3()));
+        object.insert("rot".to_string(), vec3_to_json(being.get_rot3()));
+        being_args_to_json(&being.get_being_args(), &mut object);
+        let base = world.get_base(being_type).map(|base| base.read().expect("Unable to Read Base in World To Json"));
+        let mut keys: Vec<u32> = being.get_entities().keys().cloned().collect();
+        keys.sort();
+        let mut entities = vec!();
+        for key in keys {
+            let entity = being.get_entity(key).expect("Unable to Get Entity in World To Json").read().expect("Unable to Read Entity in World To Json");
+            let base_entity = base.as_ref().and_then(|base| base.get_entity(key)).map(|base_entity| base_entity.read().expect("Unable to Read Base Entity in World To Json"));
+            let mut shared = vec!();
+            let mut own = vec!();
+            for id_type in ENTITY_ID_TYPES.iter() {
+                let is_shared = match base_entity {
+                    Some(ref base_entity) => base_entity.get_id(*id_type) == entity.get_id(*id_type),
+                    None => false,
+                };
+                if is_shared {
+                    shared.push(*id_type);
+                } else {
+                    own.push(*id_type);
+                }
+            }
+            let mut entity_object = BTreeMap::new();
+            entity_object.insert("id".to_string(), Json::U64(key as u64));
+            entity_object.insert("shared".to_string(), id_types_to_json(&shared));
+            entity_object.insert("own".to_string(), id_types_to_json(&own));
+            entity_object.insert("transforms".to_string(), transforms_to_json(&entity, &own, transforms));
+            if !entity.uses_camera() {
+                entity_object.insert("camera".to_string(), Json::Boolean(false));
+            }
+            match world.get_entity_parent(id, key) {
+                Some(parent) => {
+                    entity_object.insert("parent".to_string(), Json::U64(parent as u64));
+                },
+                None => (),
+            }
+            entities.push(Json::Object(entity_object));
+        }
+        object.insert("entities".to_string(), Json::Array(entities));
+        beings.push(Json::Object(object));
+    }
+    root.insert("beings".to_string(), Json::Array(beings));
+    format!("{}", Json::Object(root).pretty())
+}
+
+pub fn world_from_json<T: BeingType<T> + NamedBeingType>(data: &str) -> Result<SavedWorld<T>, PolyclopsError> {
+    let root = try!(Json::from_str(data).map_err(|err| PolyclopsError::WorldFormat(format!("{}", err))));
+    let version = try!(try!(get_field(&root, "version")).as_u64().ok_or(format_error("version is not a number")));
+    // older versions only lack fields that are optional below, so they still load
+    if version == 0 || version > SAVE_VERSION {
+        return Err(format_error(&format!("unsupported version {}", version)));
+    }
+    let mut bases = vec!();
+    for base in try!(get_array(&root, "bases")) {
+        bases.push(try!(being_type_from_json(base)));
+    }
+    let mut beings = vec!();
+    for being in try!(get_array(&root, "beings")) {
+        let being_args = try!(being_args_from_json(being));
+        let mut entities = vec!();
+        for entity in try!(get_array(being, "entities")) {
+            entities.push(SavedEntity {
+                id: try!(try!(get_field(entity, "id")).as_u64().ok_or(format_error("entity id is not a number"))) as u32,
+                shared: try!(id_types_from_json(try!(get_array(entity, "shared")))),
+                own: try!(id_types_from_json(try!(get_array(entity, "own")))),
+                transforms: try!(transforms_from_json(try!(get_field(entity, "transforms")))),
+                parent: match entity.find("parent") {
+                    Some(parent) => Some(try!(parent.as_u64().ok_or(format_error("entity parent is not a number"))) as u32),
+                    None => None,
+                },
+                camera: match entity.find("camera") {
+                    Some(_) => try!(get_bool(entity, "camera")),
+                    None => true,
+                },
+            });
+        }
+        beings.push(SavedBeing {
+            being_type: try!(being_type_from_json(try!(get_field(being, "type")))),
+            being_args: being_args,
+            entities: entities,
+            parent: match being.find("parent") {
+                Some(parent) => Some(try!(parent.as_u64().ok_or(format_error("parent is not a number"))) as usize),
+                None => None,
+            },
+        });
+    }
+    Ok(SavedWorld {
+        paused: match root.find("paused") {
+            Some(_) => try!(get_bool(&root, "paused")),
+            None => false,
+        },
+        tick_in_background: match root.find("tick_in_background") {
+            Some(_) => try!(get_bool(&root, "tick_in_background")),
+            None => false,
+        },
+        camera: match root.find("camera") {
+            Some(camera) => try!(camera_from_json(camera)),
+            None => Camera::default(),
+        },
+        bases: bases,
+        beings: beings,
+    })
+}
+
+// name, tags, texture and properties, only written when set
+pub fn being_args_to_json(being_args: &BeingArgs, object: &mut BTreeMap<String, Json>) {
+    match being_args.name {
+        Some(ref name) => {
+            object.insert("name".to_string(), Json::String(name.clone()));
+        },
+        None => (),
+    }
+    if !being_args.tags.is_empty() {
+        object.insert("tags".to_string(), Json::Array(being_args.tags.iter().map(|tag| Json::String(tag.clone())).collect()));
+    }
+    match being_args.texture {
+        Some(ref texture) => {
+            object.insert("texture".to_string(), Json::String(texture.clone()));
+        },
+        None => (),
+    }
+    if !being_args.properties.is_empty() {
+        let properties = being_args.properties.iter().map(|entry| (entry.0.clone(), property_to_json(entry.1))).collect();
+        object.insert("properties".to_string(), Json::Object(properties));
+    }
+}
+
+// every field is optional, missing ones are left for make_being to fill from the base
+pub fn being_args_from_json(json: &Json) -> Result<BeingArgs, PolyclopsError> {
+    if json.as_object().is_none() {
+        return Err(format_error("being is not an object"));
+    }
+    let mut being_args = BeingArgs::new();
+    match json.find("pos") {
+        Some(pos) => being_args = being_args.with_pos(try!(vec3_from_json(pos))),
+        None => (),
+    }
+    match json.find("vel") {
+        Some(vel) => being_args = being_args.with_vel(try!(vec3_from_json(vel))),
+        None => (),
+    }
+    match json.find("acc") {
+        Some(acc) => being_args = being_args.with_acc(try!(vec3_from_json(acc))),
+        None => (),
+    }
+    match json.find("sca") {
+        Some(sca) => being_args = being_args.with_sca(try!(vec3_from_json(sca))),
+        None => (),
+    }
+    match json.find("rot") {
+        Some(rot) => being_args = being_args.with_rot(try!(vec3_from_json(rot))),
+        None => (),
+    }
+    match json.find("name") {
+        Some(name) => being_args = being_args.with_name(try!(name.as_string().ok_or(format_error("name is not a string")))),
+        None => (),
+    }
+    if json.find("tags").is_some() {
+        for tag in try!(get_array(json, "tags")) {
+            being_args = being_args.with_tag(try!(tag.as_string().ok_or(format_error("tag is not a string"))));
+        }
+    }
+    match json.find("texture") {
+        Some(texture) => being_args = being_args.with_texture(try!(texture.as_string().ok_or(format_error("texture is not a string")))),
+        None => (),
+    }
+    match json.find("properties") {
+        Some(properties) => {
+            let properties = try!(properties.as_object().ok_or(format_error("properties is not an object")));
+            for entry in properties.iter() {
+                being_args = being_args.with_property(entry.0, try!(property_from_json(entry.1)));
+            }
+        },
+        None => (),
+    }
+    Ok(being_args)
+}
+
+fn property_to_json(property: &Property) -> Json {
+    match *property {
+        Property::Bool(value) => Json::Boolean(value),
+        Property::Int(value) => Json::I64(value as i64),
+        Property::Float(value) => Json::F64(value as f64),
+        Property::Text(ref value) => Json::String(value.clone()),
+        Property::Vec3(value) => vec3_to_json(value),
+    }
+}
+
+fn property_from_json(json: &Json) -> Result<Property, PolyclopsError> {
+    match *json {
+        Json::Boolean(value) => Ok(Property::Bool(value)),
+        Json::I64(value) if value >= i32::MIN as i64 && value <= i32::MAX as i64 => Ok(Property::Int(value as i32)),
+        Json::U64(value) if value <= i32::MAX as u64 => Ok(Property::Int(value as i32)),
+        Json::I64(_) | Json::U64(_) => Err(format_error("property does not fit in an int")),
+        Json::F64(value) => Ok(Property::Float(value as f32)),
+        Json::String(ref value) => Ok(Property::Text(value.clone())),
+        Json::Array(_) => Ok(Property::Vec3(try!(vec3_from_json(json)))),
+        _ => Err(format_error("property is not a bool, number, string or vec3")),
+    }
+}
+
+pub fn format_error(message: &str) -> PolyclopsError {
+    PolyclopsError::WorldFormat(message.to_string())
+}
+
+pub fn get_field<'a>(json: &'a Json, name: &str) -> Result<&'a Json, PolyclopsError> {
+    json.find(name).ok_or(format_error(&format!("missing field {}", name)))
+}
+
+pub fn get_array<'a>(json: &'a Json, name: &str) -> Result<&'a Vec<Json>, PolyclopsError> {
+    try!(get_field(json, name)).as_array().ok_or(format_error(&format!("{} is not an array", name)))
+}
+
+fn get_bool(json: &Json, name: &str) -> Result<bool, PolyclopsError> {
+    try!(get_field(json, name)).as_boolean().ok_or(format_error(&format!("{} is not a boolean", name)))
+}
+
+pub fn being_type_from_json<T: NamedBeingType>(json: &Json) -> Result<T, PolyclopsError> {
+    let name = try!(json.as_string().ok_or(format_error("being type is not a string")));
+    T::from_name(name).ok_or(format_error(&format!("unknown being type {}", name)))
+}
+
+fn floats_to_json(vals: &[f32]) -> Json {
+    Json::Array(vals.iter().map(|val| Json::F64(*val as f64)).collect())
+}
+
+fn floats_from_json(json: &Json, count: usize) -> Result<Vec<f32>, PolyclopsError> {
+    let array = try!(json.as_array().ok_or(format_error("expected an array of numbers")));
+    if array.len() != count {
+        return Err(format_error(&format!("expected {} numbers", count)));
+    }
+    let mut vals = vec!();
+    for val in array {
+        vals.push(try!(val.as_f64().ok_or(format_error("expected a number"))) as f32);
+    }
+    Ok(vals)
+}
+
+fn vec3_to_json(vec3: Vec3) -> Json {
+    floats_to_json(&vec3.get_vals())
+}
+
+pub fn vec3_from_json(json: &Json) -> Result<Vec3, PolyclopsError> {
+    let vals = try!(floats_from_json(json, 3));
+    Ok(Vec3::from([vals[0], vals[1], vals[2]]))
+}
+
+fn mat4_to_json(mat4: Mat4) -> Json {
+    let mut vals = vec!();
+    for column in 0..4 {
+        vals.extend_from_slice(&mat4[column].get_vals());
+    }
+    floats_to_json(&vals)
+}
+
+fn mat4_from_json(json: &Json) -> Result<Mat4, PolyclopsError> {
+    let vals = try!(floats_from_json(json, 16));
+    let mut columns = [[0.0; 4]; 4];
+    for column in 0..4 {
+        for row in 0..4 {
+            columns[column][row] = vals[column * 4 + row];
+        }
+    }
+    Ok(Mat4::from(columns))
+}
+
+fn camera_to_json(camera: &Camera) -> Json {
+    let mut object = BTreeMap::new();
+    object.insert("projection".to_string(), Json::String(match camera.projection {
+        Projection::Orthographic => "Orthographic",
+        Projection::Perspective => "Perspective",
+    }.to_string()));
+    object.insert("pos".to_string(), vec3_to_json(camera.pos));
+    object.insert("pitch".to_string(), Json::F64(camera.pitch as f64));
+    object.insert("yaw".to_string(), Json::F64(camera.yaw as f64));
+    object.insert("zoom".to_string(), Json::F64(camera.zoom as f64));
+    object.insert("near".to_string(), Json::F64(camera.near as f64));
+    object.insert("far".to_string(), Json::F64(camera.far as f64));
+    object.insert("field_of_view".to_string(), Json::F64(camera.field_of_view as f64));
+    Json::Object(object)
+}
+
+fn camera_from_json(json: &Json) -> Result<Camera, PolyclopsError> {
+    let projection = match try!(try!(get_field(json, "projection")).as_string().ok_or(format_error("projection is not a string"))) {
+        "Orthographic" => Projection::Orthographic,
+        "Perspective" => Projection::Perspective,
+        name => return Err(format_error(&format!("unknown projection {}", name))),
+    };
+    Ok(Camera::new(1.0)
+        .with_projection(projection)
+        .with_pos(try!(vec3_from_json(try!(get_field(json, "pos")))))
+        .with_pitch(try!(get_float(json, "pitch")))
+        .with_yaw(try!(get_float(json, "yaw")))
+        .with_zoom(try!(get_float(json, "zoom")))
+        .with_clipping(try!(get_float(json, "near")), try!(get_float(json, "far")))
+        .with_field_of_view(try!(get_float(json, "field_of_view"))))
+}
+
+fn get_float(json: &Json, name: &str) -> Result<f32, PolyclopsError> {
+    Ok(try!(try!(get_field(json, name)).as_f64().ok_or(format_error(&format!("{} is not a number", name)))) as f32)
+}
+
+fn id_type_to_name(id_type: EntityIDType) -> &'static str {
+    match id_type {
+        EntityIDType::Vertex => "Vertex",
+        EntityIDType::Index => "Index",
+        EntityIDType::Texture => "Texture",
+        EntityIDType::DrawParameter => "DrawParameter",
+        EntityIDType::Perspective => "Perspective",
+        EntityIDType::View => "View",
+        EntityIDType::Model => "Model",
+    }
+}
+
+fn id_types_to_json(id_types: &[EntityIDType]) -> Json {
+    Json::Array(id_types.iter().map(|id_type| Json::String(id_type_to_name(*id_type).to_string())).collect())
+}
+
+fn id_types_from_json(array: &Vec<Json>) -> Result<Vec<EntityIDType>, PolyclopsError> {
+    let mut id_types = vec!();
+    for json in array {
+        let name = try!(json.as_string().ok_or(format_error("entity id type is not a string")));
+        match ENTITY_ID_TYPES.iter().find(|id_type| id_type_to_name(**id_type) == name) {
+            Some(id_type) => id_types.push(*id_type),
+            None => return Err(format_error(&format!("unknown entity id type {}", name))),
+        }
+    }
+    Ok(id_types)
+}
+
+// only transforms the entity owns are saved, shared ones come back with the base
+fn transforms_to_json(entity: &Entity, own: &[EntityIDType], transforms: &Transforms) -> Json {
+    let mut object = BTreeMap::new();
+    for id_type in own {
+        let (matrix, inverse) = match *id_type {
+            EntityIDType::Perspective => (transforms.get_perspective_matrix(entity), transforms.get_perspective_inverse(entity)),
+            EntityIDType::View => (transforms.get_view_matrix(entity), transforms.get_view_inverse(entity)),
+            EntityIDType::Model => (transforms.get_model_matrix(entity), transforms.get_model_inverse(entity)),
+            _ => continue,
+        };
+        match (matrix, inverse) {
+            (Ok(matrix), Ok(inverse)) => {
+                let mut transform = BTreeMap::new();
+                transform.insert("matrix".to_string(), mat4_to_json(matrix));
+                transform.insert("inverse".to_string(), mat4_to_json(inverse));
+                object.insert(id_type_to_name(*id_type).to_string(), Json::Object(transform));
+            },
+            _ => (),
+        }
+    }
+    Json::Object(object)
+}
+
+fn transforms_from_json(json: &Json) -> Result<Vec<TransformEvent>, PolyclopsError> {
+    let mut events = vec!();
+    for name in ["Perspective", "View", "Model"].iter() {
+        match json.find(name) {
+            Some(transform) => {
+                let matrix = try!(mat4_from_json(try!(get_field(transform, "matrix"))));
+                let inverse = try!(mat4_from_json(try!(get_field(transform, "inverse"))));
+                events.push(match *name {
+                    "Perspective" => TransformEvent::Perspective(matrix, inverse),
+                    "View" => TransformEvent::View(matrix, inverse),
+                    _ => TransformEvent::Model(matrix, inverse),
+                });
+            },
+            None => (),
+        }
+    }
+    Ok(events)
+}
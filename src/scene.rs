@@ -0,0 +1,31 @@
+use rustc_serialize::json::{Json};
+
+use world::{WorldEvent, TickEvent};
+use being::{BeingType};
+use save::{NamedBeingType, get_field, get_array, being_type_from_json, being_args_from_json};
+use error::{PolyclopsError};
+
+// a scene is {"bases": [type names], "beings": [{"type": name, "pos": [x, y, z], ...}]}
+// beings may also set name, tags, texture and properties, every field but type is optional
+// and bases defaults to each type the beings use
+pub fn scene_from_json<T: BeingType<T> + NamedBeingType>(data: &str) -> Result<Vec<WorldEvent<T>>, PolyclopsError> {
+    let root = try!(Json::from_str(data).map_err(|err| PolyclopsError::WorldFormat(format!("{}", err))));
+    let mut bases: Vec<T> = vec!();
+    let mut beings = vec!();
+    for being in try!(get_array(&root, "beings")) {
+        let being_type: T = try!(being_type_from_json(try!(get_field(being, "type"))));
+        if !bases.contains(&being_type) {
+            bases.push(being_type.clone());
+        }
+        beings.push(WorldEvent::Tick(TickEvent::NewBeing(being_type, try!(being_args_from_json(being)))));
+    }
+    if root.find("bases").is_some() {
+        bases.clear();
+        for base in try!(get_array(&root, "bases")) {
+            bases.push(try!(being_type_from_json(base)));
+        }
+    }
+    let mut events: Vec<WorldEvent<T>> = bases.into_iter().map(|being_type| WorldEvent::Tick(TickEvent::NewBase(being_type))).collect();
+    events.append(&mut beings);
+    Ok(events)
+}
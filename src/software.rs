@@ -0,0 +1,304 @@
+use glium::glutin::Event as WindowEvent;
+use image::{load_from_memory, RgbaImage, Rgba};
+use std::collections::{HashMap};
+use std::sync::{Arc, RwLock};
+
+use graphics::{Renderer, Frame, Entity, Transforms, Vertex, Index, DrawMethod, DepthTestMethod, CullingMethod, get_perspective_view};
+use math::{Mat4, Vec2, Vec4};
+use utils::{ID, EntityIDType};
+use error::{PolyclopsError};
+
+pub struct Software {
+    width: u32,
+    height: u32,
+    events: Vec<WindowEvent>,
+    texture_buffers: HashMap<ID, RgbaImage>,
+    vertex_buffers: HashMap<ID, Vec<Vertex>>,
+    index_buffers: HashMap<ID, Vec<Index>>,
+    draw_methods: HashMap<ID, DrawMethod>,
+    image: RgbaImage,
+}
+
+impl Software {
+    pub fn new(width: u32, height: u32) -> Software {
+        Software {
+            width: width,
+            height: height,
+            events: vec!(),
+            texture_buffers: HashMap::new(),
+            vertex_buffers: HashMap::new(),
+            index_buffers: HashMap::new(),
+            draw_methods: HashMap::new(),
+            image: RgbaImage::from_pixel(width, height, Rgba { data: [0, 0, 0, 255] }),
+        }
+    }
+
+    pub fn push_event(&mut self, event: WindowEvent) {
+        self.events.push(event);
+    }
+
+    pub fn get_image(&self) -> &RgbaImage {
+        &self.image
+    }
+}
+
+impl Renderer for Software {
+    fn get_resolution_vec2(&self) -> Vec2 {
+        Vec2::from([self.width as f32, self.height as f32])
+    }
+
+    fn set_resolution(&mut self, resolution: (u32, u32)) {
+        self.width = resolution.0;
+        self.height = resolution.1;
+        self.image = RgbaImage::from_pixel(self.width, self.height, Rgba { data: [0, 0, 0, 255] });
+    }
+
+    fn poll_events(&mut self) -> Vec<WindowEvent> {
+        self.events.drain(..).collect()
+    }
+
+    fn frame<'a>(&'a mut self) -> Box<Frame + 'a> {
+        let size = (self.width * self.height) as usize;
+        Box::new(SoftwareFrame {
+            color: RgbaImage::from_pixel(self.width, self.height, Rgba { data: [0, 0, 0, 255] }),
+            depth: vec![1.0; size],
+            software: self,
+            camera: None,
+        })
+    }
+
+    fn set_vertices(&mut self, entity: &Arc<RwLock<Entity>>, vertices: Vec<Vertex>) -> Result<(), PolyclopsError> {
+        self.vertex_buffers.insert(entity.read().expect("Unable to Read Entity in Set Vertices in Software").get_vertex_id(), vertices);
+        Ok(())
+    }
+
+    fn set_indices(&mut self, entity: &Arc<RwLock<Entity>>, indices: Vec<Index>) -> Result<(), PolyclopsError> {
+        self.index_buffers.insert(entity.read().expect("Unable to Read Entity in Set Indices in Software").get_index_id(), indices);
+        Ok(())
+    }
+
+    fn set_texture(&mut self, entity: &Arc<RwLock<Entity>>, data: &[u8]) -> Result<(), PolyclopsError> {
+        let texture = try!(load_from_memory(data).map_err(|err| PolyclopsError::TextureDecode(format!("{:?}", err)))).to_rgba();
+        self.texture_buffers.insert(entity.read().expect("Unable to Read Entity in Set Texture in Software").get_texture_id(), texture);
+        Ok(())
+    }
+
+    fn free_buffer(&mut self, id_type: EntityIDType, id: ID) {
+        match id_type {
+            EntityIDType::Vertex => {
+                self.vertex_buffers.remove(&id);
+            },
+            EntityIDType::Index => {
+                self.index_buffers.remove(&id);
+            },
+            EntityIDType::Texture => {
+                self.texture_buffers.remove(&id);
+            },
+            EntityIDType::DrawParameter => {
+                self.draw_methods.remove(&id);
+            },
+            EntityIDType::Perspective | EntityIDType::View | EntityIDType::Model => (),
+        }
+    }
+
+    fn set_draw_parameters(&mut self, entity: &Arc<RwLock<Entity>>, draw_method: DrawMethod) {
+        self.draw_methods.insert(entity.read().expect("Unable to Read Entity in Set Draw Parameters in Software").get_draw_parameters_id(), draw_method);
+    }
+}
+
+pub struct SoftwareFrame<'a> {
+    software: &'a mut Software,
+    color: RgbaImage,
+    depth: Vec<f32>,
+    camera: Option<(Mat4, Mat4)>,
+}
+
+#[derive(Copy, Clone)]
+struct ClipVertex {
+    clip: Vec4,
+    tex_coord: [f32; 2],
+}
+
+struct ScreenVertex {
+    x: f32,
+    y: f32,
+    z: f32,
+    w_inverse: f32,
+    tex_coord: [f32; 2],
+}
+
+impl<'a> Frame for SoftwareFrame<'a> {
+    fn draw_entity_with_model(&mut self, entity_arc: &Arc<RwLock<Entity>>, transforms: &Arc<RwLock<Transforms>>, model: Mat4) -> Result<(), PolyclopsError> {
+        let entity = entity_arc.read().expect("Unable to Read Entity in Draw Entity in Software");
+        let matrix = {
+            let (perspective, view) = try!(get_perspective_view(&entity, &transforms.read().expect("Unable to Read Transforms in Draw Entity in Software"), self.camera));
+            (perspective, view, model)
+        };
+        let software: &Software = self.software;
+        let size = (software.width, software.height);
+        let (depth_test, culling) = split_method(try!(software.draw_methods.get(&entity.get_draw_parameters_id()).ok_or(PolyclopsError::MissingBuffer(entity.get_draw_parameters_id()))).clone());
+        let clip: Vec<ClipVertex> = try!(software.vertex_buffers.get(&entity.get_vertex_id()).ok_or(PolyclopsError::MissingBuffer(entity.get_vertex_id()))).iter().map(|vertex| {
+            let position = vertex.get_position();
+            ClipVertex {
+                clip: transform(&matrix.0, transform(&matrix.1, transform(&matrix.2, Vec4::from([position[0], position[1], position[2], 1.0])))),
+                tex_coord: vertex.get_tex_coord(),
+            }
+        }).collect();
+        let indices = try!(software.index_buffers.get(&entity.get_index_id()).ok_or(PolyclopsError::MissingBuffer(entity.get_index_id())));
+        let texture = try!(software.texture_buffers.get(&entity.get_texture_id()).ok_or(PolyclopsError::MissingBuffer(entity.get_texture_id())));
+        for triangle in indices.chunks(3) {
+            if triangle.len() < 3 {
+                break;
+            }
+            let polygon = clip_near([clip[triangle[0] as usize], clip[triangle[1] as usize], clip[triangle[2] as usize]]);
+            let screen: Vec<ScreenVertex> = polygon.into_iter().map(|vertex| to_screen(vertex.clip, vertex.tex_coord, size)).collect();
+            // clipping a triangle leaves a convex polygon with the same winding, drawn as a fan
+            for index in 1..screen.len().saturating_sub(1) {
+                let vertices = [&screen[0], &screen[index], &screen[index + 1]];
+                // only the near plane is clipped, a vertex far enough past the far plane can still have a negative w
+                if vertices.iter().any(|vertex| !(vertex.w_inverse > 0.0)) {
+                    continue;
+                }
+                // screen y points down, so a counter clockwise triangle in gl space has a negative area here
+                let area = edge(vertices[0], vertices[1], vertices[2].x, vertices[2].y);
+                let culled = match culling {
+                    Some(CullingMethod::Clockwise) => area > 0.0,
+                    Some(CullingMethod::CounterClockwise) => area < 0.0,
+                    None => false,
+                };
+                if !culled {
+                    draw_triangle(&mut self.color, &mut self.depth, vertices, texture, &depth_test);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn set_camera(&mut self, perspective: Mat4, view: Mat4) {
+        self.camera = Some((perspective, view));
+    }
+
+    fn end(self: Box<Self>) {
+        let frame = *self;
+        frame.software.image = frame.color;
+    }
+}
+
+// cuts the triangle at the near plane, z = -w in clip space, so parts behind the camera never reach the divide by w
+fn clip_near(triangle: [ClipVertex; 3]) -> Vec<ClipVertex> {
+    let mut polygon = vec!();
+    for index in 0..3 {
+        let current = triangle[index];
+        let next = triangle[(index + 1) % 3];
+        let current_distance = current.clip[2] + current.clip[3];
+        let next_distance = next.clip[2] + next.clip[3];
+        if current_distance >= 0.0 {
+            polygon.push(current);
+        }
+        if (current_distance >= 0.0) != (next_distance >= 0.0) {
+            let t = current_distance / (current_distance - next_distance);
+            let mut clip = Vec4::zero();
+            for component in 0..4 {
+                clip[component] = current.clip[component] + (next.clip[component] - current.clip[component]) * t;
+            }
+            polygon.push(ClipVertex {
+                clip: clip,
+                tex_coord: [
+                    current.tex_coord[0] + (next.tex_coord[0] - current.tex_coord[0]) * t,
+                    current.tex_coord[1] + (next.tex_coord[1] - current.tex_coord[1]) * t,
+                ],
+            });
+        }
+    }
+    polygon
+}
+
+fn to_screen(clip: Vec4, tex_coord: [f32; 2], size: (u32, u32)) -> ScreenVertex {
+    let w_inverse = 1.0 / clip[3];
+    ScreenVertex {
+        x: (clip[0] * w_inverse + 1.0) * 0.5 * size.0 as f32,
+        y: (1.0 - clip[1] * w_inverse) * 0.5 * size.1 as f32,
+        z: clip[2] * w_inverse * 0.5 + 0.5,
+        w_inverse: w_inverse,
+        tex_coord: tex_coord,
+    }
+}
+
+fn draw_triangle(color: &mut RgbaImage, depth: &mut Vec<f32>, vertices: [&ScreenVertex; 3], texture: &RgbaImage, depth_test: &Option<DepthTestMethod>) {
+    let area = edge(vertices[0], vertices[1], vertices[2].x, vertices[2].y);
+    if area == 0.0 {
+        return;
+    }
+    let (width, height) = color.dimensions();
+    let min_x = vertices.iter().fold(width as f32, |acc, v| acc.min(v.x)).max(0.0).floor() as u32;
+    let max_x = vertices.iter().fold(0.0f32, |acc, v| acc.max(v.x)).min(width as f32).ceil() as u32;
+    let min_y = vertices.iter().fold(height as f32, |acc, v| acc.min(v.y)).max(0.0).floor() as u32;
+    let max_y = vertices.iter().fold(0.0f32, |acc, v| acc.max(v.y)).min(height as f32).ceil() as u32;
+    for y in min_y..max_y {
+        for x in min_x..max_x {
+            let px = x as f32 + 0.5;
+            let py = y as f32 + 0.5;
+            let b0 = edge(vertices[1], vertices[2], px, py) / area;
+            let b1 = edge(vertices[2], vertices[0], px, py) / area;
+            let b2 = edge(vertices[0], vertices[1], px, py) / area;
+            if b0 < 0.0 || b1 < 0.0 || b2 < 0.0 {
+                continue;
+            }
+            let z = b0 * vertices[0].z + b1 * vertices[1].z + b2 * vertices[2].z;
+            if z < 0.0 || z > 1.0 {
+                continue;
+            }
+            let index = (y * width + x) as usize;
+            match *depth_test {
+                Some(DepthTestMethod::IfLess) => {
+                    if z >= depth[index] {
+                        continue;
+                    }
+                    depth[index] = z;
+                },
+                None => (),
+            }
+            let p0 = b0 * vertices[0].w_inverse;
+            let p1 = b1 * vertices[1].w_inverse;
+            let p2 = b2 * vertices[2].w_inverse;
+            let sum = p0 + p1 + p2;
+            let u = (p0 * vertices[0].tex_coord[0] + p1 * vertices[1].tex_coord[0] + p2 * vertices[2].tex_coord[0]) / sum;
+            let v = (p0 * vertices[0].tex_coord[1] + p1 * vertices[1].tex_coord[1] + p2 * vertices[2].tex_coord[1]) / sum;
+            color.put_pixel(x, y, sample(texture, u, v));
+        }
+    }
+}
+
+fn edge(a: &ScreenVertex, b: &ScreenVertex, x: f32, y: f32) -> f32 {
+    (b.x - a.x) * (y - a.y) - (b.y - a.y) * (x - a.x)
+}
+
+fn transform(matrix: &Mat4, vec4: Vec4) -> Vec4 {
+    let mut new = Vec4::zero();
+    for row in 0..4 {
+        let mut dot = 0.0;
+        for column in 0..4 {
+            dot += matrix[column][row] * vec4[column];
+        }
+        new[row] = dot;
+    }
+    new
+}
+
+fn sample(texture: &RgbaImage, u: f32, v: f32) -> Rgba<u8> {
+    let (width, height) = texture.dimensions();
+    let u = u - u.floor();
+    let v = v - v.floor();
+    let x = ((u * width as f32) as u32).min(width - 1);
+    let y = (((1.0 - v) * height as f32) as u32).min(height - 1);
+    *texture.get_pixel(x, y)
+}
+
+fn split_method(method: DrawMethod) -> (Option<DepthTestMethod>, Option<CullingMethod>) {
+    match method {
+        DrawMethod::Both(depth, cull) => (Some(depth), Some(cull)),
+        DrawMethod::Depth(depth) => (Some(depth), None),
+        DrawMethod::Culling(cull) => (None, Some(cull)),
+        DrawMethod::Neither => (None, None),
+    }
+}
@@ -0,0 +1,169 @@
+use std::sync::atomic::{AtomicUsize, ATOMIC_USIZE_INIT, Ordering};
+use std::fmt::Write;
+use time::{precise_time_s};
+
+use utils::{ID, UNSET};
+
+static NEXT_THREAD_INDEX: AtomicUsize = ATOMIC_USIZE_INIT;
+
+thread_local!(static THREAD_INDEX: usize = NEXT_THREAD_INDEX.fetch_add(1, Ordering::Relaxed));
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum TracePhase {
+    Tick,
+    TickAfter,
+}
+
+impl TracePhase {
+    fn get_name(&self) -> &'static str {
+        match *self {
+            TracePhase::Tick => "tick",
+            TracePhase::TickAfter => "tick_after",
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct TraceEntry {
+    pub tick: u64,
+    pub world_id: ID,
+    pub phase: TracePhase,
+    pub name: &'static str,
+    pub target: Option<ID>,
+    pub rank: u32,
+    pub thread: usize,
+    // seconds since the tracer was created
+    pub start: f64,
+    pub duration: f64,
+}
+
+#[derive(Clone)]
+pub struct TraceBatch {
+    entries: Vec<TraceEntry>,
+    start_time: f64,
+}
+
+impl TraceBatch {
+    pub fn get_time(&self) -> f64 {
+        precise_time_s() - self.start_time
+    }
+
+    // the tick and world are filled in when the batch is merged
+    pub fn record(&mut self, phase: TracePhase, name: &'static str, target: Option<ID>, rank: u32, start: f64) {
+        let duration = self.get_time() - start;
+        let entry = TraceEntry {
+            tick: 0,
+            world_id: UNSET,
+            phase: phase,
+            name: name,
+            target: target,
+            rank: rank,
+            thread: THREAD_INDEX.with(|index| *index),
+            start: start,
+            duration: duration,
+        };
+        self.entries.push(entry);
+    }
+
+    // an empty batch timed against the same start, for handing to another worker
+    pub fn new_empty(&self) -> TraceBatch {
+        TraceBatch {
+            entries: vec!(),
+            start_time: self.start_time,
+        }
+    }
+
+    pub fn append(&mut self, other: TraceBatch) {
+        self.entries.extend(other.entries);
+    }
+}
+
+pub struct Tracer {
+    entries: Vec<TraceEntry>,
+    tick: u64,
+    world_id: ID,
+    start_time: f64,
+}
+
+impl Tracer {
+    pub fn new() -> Tracer {
+        Tracer {
+            entries: vec!(),
+            tick: 0,
+            world_id: UNSET,
+            start_time: precise_time_s(),
+        }
+    }
+
+    pub fn get_entries(&self) -> &Vec<TraceEntry> {
+        &self.entries
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    // entries recorded after this belong to the given world's next tick
+    pub fn begin_tick(&mut self, world_id: ID) {
+        self.tick += 1;
+        self.world_id = world_id;
+    }
+
+    pub fn get_time(&self) -> f64 {
+        precise_time_s() - self.start_time
+    }
+
+    // workers record into their own batch so only the merge takes the tracer's lock
+    pub fn new_batch(&self) -> TraceBatch {
+        TraceBatch {
+            entries: vec!(),
+            start_time: self.start_time,
+        }
+    }
+
+    pub fn merge(&mut self, batch: TraceBatch) {
+        for mut entry in batch.entries {
+            entry.tick = self.tick;
+            entry.world_id = self.world_id;
+            self.entries.push(entry);
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        json.push_str("[\n");
+        for (index, entry) in self.entries.iter().enumerate() {
+            if index != 0 {
+                json.push_str(",\n");
+            }
+            write!(json, "  {{\"tick\": {}, \"world\": {}, \"phase\": \"{}\", \"name\": \"{}\", \"target\": {}, \"rank\": {}, \"thread\": {}, \"start\": {}, \"duration\": {}}}",
+                entry.tick, entry.world_id.get_id(), entry.phase.get_name(), entry.name, target_to_json(entry.target), entry.rank, entry.thread, entry.start, entry.duration
+            ).expect("Unable to Write Entry in To Json");
+        }
+        json.push_str("\n]\n");
+        json
+    }
+
+    // chrome://tracing and compatible viewers, one process per world and one lane per thread
+    pub fn to_chrome_trace(&self) -> String {
+        let mut json = String::new();
+        json.push_str("{\"traceEvents\": [\n");
+        for (index, entry) in self.entries.iter().enumerate() {
+            if index != 0 {
+                json.push_str(",\n");
+            }
+            write!(json, "  {{\"name\": \"{}\", \"cat\": \"{}\", \"ph\": \"X\", \"ts\": {}, \"dur\": {}, \"pid\": {}, \"tid\": {}, \"args\": {{\"tick\": {}, \"rank\": {}, \"target\": {}}}}}",
+                entry.name, entry.phase.get_name(), entry.start * 1000000.0, entry.duration * 1000000.0, entry.world_id.get_id(), entry.thread, entry.tick, entry.rank, target_to_json(entry.target)
+            ).expect("Unable to Write Entry in To Chrome Trace");
+        }
+        json.push_str("\n]}\n");
+        json
+    }
+}
+
+fn target_to_json(target: Option<ID>) -> String {
+    match target {
+        Some(id) => format!("\"{}\"", id),
+        None => "null".to_string(),
+    }
+}
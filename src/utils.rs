@@ -1,33 +1,61 @@
-use std::collections::{HashMap};
 use std::fmt::{Display, Formatter, Error};
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering};
 
-#[derive(Copy, Clone, Eq, Hash, PartialEq)]
+use record::{Encode, Decode, Reader};
+use error::{PolyclopsError};
+
+// an index plus how many times that index has been handed out before, so a stale ID never equals a recycled one
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, Ord, PartialOrd)]
 pub struct ID {
     id: IDSize,
+    generation: IDSize,
 }
 
 impl ID {
-    pub fn new(manager: Arc<RwLock<IDManager>>, id_type: IDType) -> ID {
-        ID {
-            id: manager.write().expect("Unable to Write Manager in New ID").get_id(id_type),
-        }
+    pub fn new(manager: Arc<IDManager>, id_type: IDType) -> ID {
+        manager.get_id(id_type)
     }
 
     pub fn get_id(&self) -> IDSize {
         self.id
     }
+
+    pub fn get_generation(&self) -> IDSize {
+        self.generation
+    }
 }
 
 pub const UNSET: ID = ID{
     id: UNSET_ID,
+    generation: 0,
 };
 
 pub const UNSET_ID: IDSize = 0;
 
+impl Encode for ID {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.id.encode(out);
+        self.generation.encode(out);
+    }
+}
+
+impl Decode for ID {
+    fn decode(reader: &mut Reader) -> Result<ID, PolyclopsError> {
+        Ok(ID {
+            id: try!(IDSize::decode(reader)),
+            generation: try!(IDSize::decode(reader)),
+        })
+    }
+}
+
 impl Display for ID {
     fn fmt(&self, f: &mut Formatter) -> Result<(), Error>{
-        write!(f, "{}", self.id)
+        if self.generation == 0 {
+            write!(f, "{}", self.id)
+        } else {
+            write!(f, "{}v{}", self.id, self.generation)
+        }
     }
 }
 
@@ -39,6 +67,20 @@ pub enum IDType {
     World,
 }
 
+// every entity id type, then EntityContainer, Being and World
+const ID_TYPE_COUNT: usize = ENTITY_ID_TYPES.len() + 3;
+
+impl IDType {
+    fn get_index(&self) -> usize {
+        match *self {
+            IDType::Entity(entity_id_type) => entity_id_type as usize,
+            IDType::EntityContainer => ENTITY_ID_TYPES.len(),
+            IDType::Being => ENTITY_ID_TYPES.len() + 1,
+            IDType::World => ENTITY_ID_TYPES.len() + 2,
+        }
+    }
+}
+
 #[derive(Copy, Clone, Eq, Hash, PartialEq)]
 pub enum EntityIDType {
     Vertex,
@@ -50,24 +92,73 @@ pub enum EntityIDType {
     Model,
 }
 
+pub const ENTITY_ID_TYPES: [EntityIDType; 7] = [
+    EntityIDType::Vertex,
+    EntityIDType::Index,
+    EntityIDType::Texture,
+    EntityIDType::DrawParameter,
+    EntityIDType::Perspective,
+    EntityIDType::View,
+    EntityIDType::Model,
+];
+
+// new IDs come from an atomic counter per IDType, so any number of threads can allocate through a shared Arc without waiting
 pub struct IDManager {
-    map: HashMap<IDType, IDSize>,
+    next: Vec<AtomicUsize>,
+    free_counts: Vec<AtomicUsize>,
+    free: Vec<Mutex<Vec<ID>>>,
 }
 
 impl IDManager {
     pub fn new() -> IDManager {
         IDManager {
-            map: HashMap::new(),
+            next: (0..ID_TYPE_COUNT).map(|_| AtomicUsize::new(1)).collect(),
+            free_counts: (0..ID_TYPE_COUNT).map(|_| AtomicUsize::new(0)).collect(),
+            free: (0..ID_TYPE_COUNT).map(|_| Mutex::new(vec!())).collect(),
         }
     }
 
-    fn get_id(&mut self, id_type: IDType) -> IDSize {
-        let id = match self.map.get(&id_type) {
-            Some(id) => *id,
-            None => 1,
-        };
-        self.map.insert(id_type, id + 1);
-        id
+    fn get_id(&self, id_type: IDType) -> ID {
+        let index = id_type.get_index();
+        // only lock the free list when something has been freed, so allocating without frees never waits
+        if self.free_counts[index].load(Ordering::Acquire) > 0 {
+            let mut free = self.free[index].lock().expect("Unable to Lock Free IDs in Get ID");
+            match free.pop() {
+                Some(id) => {
+                    self.free_counts[index].fetch_sub(1, Ordering::Release);
+                    return ID {
+                        id: id.id,
+                        generation: id.generation.wrapping_add(1),
+                    };
+                },
+                None => (),
+            }
+        }
+        // checked before the counter moves, so a failed allocation leaves it at the limit
+        let mut id = self.next[index].load(Ordering::Relaxed);
+        loop {
+            if id > IDSize::max_value() as usize {
+                panic!("Unable to Allocate ID in Get ID, all IDs are in use");
+            }
+            match self.next[index].compare_exchange_weak(id, id + 1, Ordering::Relaxed, Ordering::Relaxed) {
+                Ok(_) => break,
+                Err(current) => id = current,
+            }
+        }
+        ID {
+            id: id as IDSize,
+            generation: 0,
+        }
+    }
+
+    // hands the index back out with the next generation, the freed ID must not be used again
+    pub fn free_id(&self, id_type: IDType, id: ID) {
+        if id == UNSET {
+            return;
+        }
+        let index = id_type.get_index();
+        self.free[index].lock().expect("Unable to Lock Free IDs in Free ID").push(id);
+        self.free_counts[index].fetch_add(1, Ordering::Release);
     }
 }
 
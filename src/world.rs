@@ -1,5 +1,7 @@
-use std::collections::{HashMap};
-use std::sync::{Arc, RwLock};
+use std::borrow::{Cow};
+use std::mem;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, RwLock, Mutex};
 use glium::glutin::ElementState as GliumElementState;
 use glium::glutin::MouseButton as GliumMouseButton;
 use glium::glutin::VirtualKeyCode as GliumKeyCode;
@@ -10,6 +12,8 @@ use math::{Vec2, Vec3, Mat4};
 use being::{Being, BeingType};
 use keyboard::{Keyboard};
 use being_args::{BeingArgs};
+use camera::{Camera};
+use error::{PolyclopsError};
 
 pub struct World<T: BeingType<T>> {
     beings: HashMap<ID, Arc<RwLock<Box<Being<T>>>>>,
@@ -20,6 +24,19 @@ pub struct World<T: BeingType<T>> {
     aspect_ratio: f32,
     mouse_buttons: HashMap<GliumMouseButton, GliumElementState>,
     keyboard: Keyboard,
+    paused: bool,
+    tick_in_background: bool,
+    messages: HashMap<ID, Vec<T::Event>>,
+    pending_messages: HashMap<ID, Vec<T::Event>>,
+    parents: HashMap<ID, ID>,
+    children: HashMap<ID, Vec<ID>>,
+    entity_parents: HashMap<(ID, u32), u32>,
+    sleeping: HashSet<ID>,
+    // beings woken since the last update_sleeping, behind a mutex so events can wake beings through a read lock
+    woken: Mutex<HashSet<ID>>,
+    // beings whose pos, sca, rot or entity ids changed since the last take_dirty, so only they get new model matrices
+    dirty: Mutex<HashSet<ID>>,
+    camera: Camera,
 }
 
 impl<T: BeingType<T>> World<T> {
@@ -33,6 +50,17 @@ impl<T: BeingType<T>> World<T> {
             aspect_ratio: resolution[0] / resolution[1],
             mouse_buttons: HashMap::new(),
             keyboard: Keyboard::new(),
+            paused: false,
+            tick_in_background: false,
+            messages: HashMap::new(),
+            pending_messages: HashMap::new(),
+            parents: HashMap::new(),
+            children: HashMap::new(),
+            entity_parents: HashMap::new(),
+            sleeping: HashSet::new(),
+            woken: Mutex::new(HashSet::new()),
+            dirty: Mutex::new(HashSet::new()),
+            camera: Camera::new(resolution[0] / resolution[1]),
         }
     }
 
@@ -40,26 +68,202 @@ impl<T: BeingType<T>> World<T> {
         &self.beings
     }
 
+    // new beings are awake for at least their first step and have no model matrix yet
     pub fn add_being(&mut self, being: Box<Being<T>>) {
+        self.wake(being.get_id());
+        self.mark_dirty(being.get_id());
         self.beings.insert(being.get_id(), Arc::new(RwLock::new(being)));
     }
 
+    // children of the removed being are detached and stay where they are in the world
     pub fn del_being(&mut self, id: ID) {
         self.beings.remove(&id);
+        self.sleeping.remove(&id);
+        self.messages.remove(&id);
+        self.pending_messages.remove(&id);
+        self.detach(id);
+        match self.children.remove(&id) {
+            Some(children) => for child in children {
+                self.parents.remove(&child);
+            },
+            None => (),
+        }
+        let entity_keys: Vec<(ID, u32)> = self.entity_parents.keys().filter(|key| key.0 == id).cloned().collect();
+        for key in entity_keys {
+            self.entity_parents.remove(&key);
+        }
+    }
+
+    // the child's model matrix is composed with the parent's pos, sca and rot when drawn
+    pub fn attach(&mut self, child: ID, parent: ID) -> Result<(), PolyclopsError> {
+        try!(self.find_being(child));
+        try!(self.find_being(parent));
+        let mut ancestor = Some(parent);
+        while let Some(id) = ancestor {
+            if id == child {
+                return Err(PolyclopsError::InvalidParent(parent));
+            }
+            ancestor = self.get_parent(id);
+        }
+        self.detach(child);
+        self.parents.insert(child, parent);
+        self.children.entry(parent).or_insert(vec!()).push(child);
+        Ok(())
+    }
+
+    pub fn detach(&mut self, child: ID) {
+        match self.parents.remove(&child) {
+            Some(parent) => {
+                let empty = match self.children.get_mut(&parent) {
+                    Some(children) => {
+                        children.retain(|id| *id != child);
+                        children.is_empty()
+                    },
+                    None => false,
+                };
+                if empty {
+                    self.children.remove(&parent);
+                }
+            },
+            None => (),
+        }
+    }
+
+    pub fn get_parent(&self, id: ID) -> Option<ID> {
+        self.parents.get(&id).cloned()
+    }
+
+    pub fn get_children(&self, id: ID) -> &[ID] {
+        match self.children.get(&id) {
+            Some(children) => children,
+            None => &[],
+        }
+    }
+
+    // the child entity's model matrix is composed with the parent entity's model matrix when drawn
+    pub fn attach_entity(&mut self, being_id: ID, child: u32, parent: u32) -> Result<(), PolyclopsError> {
+        {
+            let being = try!(self.find_being(being_id)).read().expect("Unable to Read Being in Attach Entity");
+            try!(being.find_entity(child));
+            try!(being.find_entity(parent));
+        }
+        let mut ancestor = Some(parent);
+        while let Some(id) = ancestor {
+            if id == child {
+                return Err(PolyclopsError::InvalidParent(being_id));
+            }
+            ancestor = self.get_entity_parent(being_id, id);
+        }
+        self.entity_parents.insert((being_id, child), parent);
+        Ok(())
     }
 
+    pub fn detach_entity(&mut self, being_id: ID, child: u32) {
+        self.entity_parents.remove(&(being_id, child));
+    }
+
+    pub fn get_entity_parent(&self, being_id: ID, entity_id: u32) -> Option<u32> {
+        self.entity_parents.get(&(being_id, entity_id)).cloned()
+    }
+
+    // the generation is part of the key, so an ID kept past EndBeing misses instead of finding whichever being reused its index
     pub fn get_being(&self, id: ID) -> Option<&Arc<RwLock<Box<Being<T>>>>> {
         self.beings.get(&id)
     }
 
+    pub fn is_alive(&self, id: ID) -> bool {
+        self.beings.contains_key(&id)
+    }
+
+    pub fn find_being(&self, id: ID) -> Result<&Arc<RwLock<Box<Being<T>>>>, PolyclopsError> {
+        self.get_being(id).ok_or(PolyclopsError::MissingBeing(id))
+    }
+
+    // messages sent this tick, readable by the target during the next tick
+    pub fn get_messages(&self, id: ID) -> &[T::Event] {
+        match self.messages.get(&id) {
+            Some(messages) => messages,
+            None => &[],
+        }
+    }
+
+    pub fn send_message(&mut self, target: ID, message: T::Event) -> Result<(), PolyclopsError> {
+        if !self.beings.contains_key(&target) {
+            return Err(PolyclopsError::MissingBeing(target));
+        }
+        self.pending_messages.entry(target).or_insert(vec!()).push(message);
+        self.wake(target);
+        Ok(())
+    }
+
+    pub fn broadcast_message(&mut self, being_type: T, message: T::Event) {
+        let mut targets = vec!();
+        for entry in self.beings.iter() {
+            if entry.1.read().expect("Unable to Read Being in Broadcast Message").get_type() == being_type {
+                targets.push(*entry.0);
+            }
+        }
+        for target in targets {
+            self.pending_messages.entry(target).or_insert(vec!()).push(message.clone());
+            self.wake(target);
+        }
+    }
+
+    pub fn deliver_messages(&mut self) {
+        self.messages.clear();
+        for (target, messages) in self.pending_messages.drain() {
+            self.messages.insert(target, messages);
+        }
+    }
+
+    // sleeping beings are skipped by tick, tick after and integration but still drawn
+    pub fn is_sleeping(&self, id: ID) -> bool {
+        self.sleeping.contains(&id) && !self.woken.lock().expect("Unable to Lock Woken in Is Sleeping").contains(&id)
+    }
+
+    pub fn wake(&self, id: ID) {
+        self.woken.lock().expect("Unable to Lock Woken in Wake").insert(id);
+    }
+
+    pub fn mark_dirty(&self, id: ID) {
+        self.dirty.lock().expect("Unable to Lock Dirty in Mark Dirty").insert(id);
+    }
+
+    pub fn take_dirty(&self) -> HashSet<ID> {
+        mem::replace(&mut *self.dirty.lock().expect("Unable to Lock Dirty in Take Dirty"), HashSet::new())
+    }
+
+    // beings that can sleep, were not woken this step and are not moving fall asleep
+    pub fn update_sleeping(&mut self) {
+        let woken: HashSet<ID> = self.woken.lock().expect("Unable to Lock Woken in Update Sleeping").drain().collect();
+        for entry in self.beings.iter() {
+            if woken.contains(entry.0) {
+                self.sleeping.remove(entry.0);
+                continue;
+            }
+            let being = entry.1.read().expect("Unable to Read Being in Update Sleeping");
+            if being.can_sleep() && being.get_vel3() == Vec3::zero() && being.get_acc3() == Vec3::zero() {
+                self.sleeping.insert(*entry.0);
+            }
+        }
+    }
+
     pub fn set_base(&mut self, being_type: T, base: Box<Being<T>>) {
         self.bases.insert(being_type, Arc::new(RwLock::new(base)));
     }
 
+    pub fn get_bases(&self) -> &HashMap<T, Arc<RwLock<Box<Being<T>>>>> {
+        &self.bases
+    }
+
     pub fn get_base(&self, being_type: T) -> Option<&Arc<RwLock<Box<Being<T>>>>> {
         self.bases.get(&being_type)
     }
 
+    pub fn find_base(&self, being_type: T) -> Result<&Arc<RwLock<Box<Being<T>>>>, PolyclopsError> {
+        self.get_base(being_type).ok_or(PolyclopsError::MissingBase)
+    }
+
     pub fn update_keyboard(&mut self, key: GliumKeyCode, state: GliumElementState) {
         self.keyboard.set_key_state(key, state);
     }
@@ -71,6 +275,7 @@ impl<T: BeingType<T>> World<T> {
     pub fn update_resolution(&mut self, resolution: Vec2, aspect_ratio: f32) {
         self.resolution = resolution;
         self.aspect_ratio = aspect_ratio;
+        self.camera.aspect_ratio = aspect_ratio;
     }
 
     pub fn update_mouse_pos(&mut self, mouse_pos: Vec2) {
@@ -78,6 +283,22 @@ impl<T: BeingType<T>> World<T> {
         self.mouse_pos_world = self.screen_to_world_point(mouse_pos);
     }
 
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn set_tick_in_background(&mut self, tick_in_background: bool) {
+        self.tick_in_background = tick_in_background;
+    }
+
+    pub fn is_ticking_in_background(&self) -> bool {
+        self.tick_in_background
+    }
+
     pub fn get_key(&self, key: GliumKeyCode) -> GliumElementState {
         self.keyboard.is_key_down(key)
     }
@@ -89,6 +310,14 @@ impl<T: BeingType<T>> World<T> {
         }
     }
 
+    pub fn get_camera(&self) -> &Camera {
+        &self.camera
+    }
+
+    pub fn set_camera(&mut self, camera: Camera) {
+        self.camera = camera;
+    }
+
     pub fn get_resolution(&self) -> Vec2 {
         self.resolution
     }
@@ -184,6 +413,16 @@ pub fn get_rank_tick<T: BeingType<T>>(event: TickEvent<T>) -> u32 {
         },
         TickEvent::Transform(_, _, _) => 1,
         TickEvent::TransformBase(_, _, _) => 1,
+        TickEvent::SetPaused(_) => 0,
+        TickEvent::SetActiveWorld(_) => 0,
+        TickEvent::Message(_, _) => 0,
+        TickEvent::Broadcast(_, _) => 0,
+        TickEvent::Attach(_, _) => 0,
+        TickEvent::Detach(_) => 0,
+        TickEvent::AttachEntity(_, _, _) => 0,
+        TickEvent::DetachEntity(_, _) => 0,
+        TickEvent::SetCamera(_) => 0,
+        TickEvent::Custom(event) => T::get_custom_event_rank(&event),
     }
 }
 
@@ -200,6 +439,24 @@ pub enum TickAfterEvent<T: BeingType<T>> {
     EndBeing(ID),
 }
 
+impl<T: BeingType<T>> TickAfterEvent<T> {
+    pub fn get_target(&self) -> Option<ID> {
+        match *self {
+            TickAfterEvent::Entity(id, _, _) => Some(id),
+            TickAfterEvent::EntityBase(_, _, _) => None,
+            TickAfterEvent::EndBeing(id) => Some(id),
+        }
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match *self {
+            TickAfterEvent::Entity(_, _, _) => "Entity",
+            TickAfterEvent::EntityBase(_, _, _) => "EntityBase",
+            TickAfterEvent::EndBeing(_) => "EndBeing",
+        }
+    }
+}
+
 #[derive(Clone)]
 pub enum TickEvent<T: BeingType<T>> {
     NewBeing(T, BeingArgs),
@@ -218,6 +475,88 @@ pub enum TickEvent<T: BeingType<T>> {
     EntityIDBase(T, u32, EntityIDEvent<T>),
     Transform(ID, u32, TransformEvent),
     TransformBase(T, u32, TransformEvent),
+    SetPaused(bool),
+    SetActiveWorld(ID),
+    Message(ID, T::Event),
+    Broadcast(T, T::Event),
+    Custom(T::Event),
+    Attach(ID, ID),
+    Detach(ID),
+    AttachEntity(ID, u32, u32),
+    DetachEntity(ID, u32),
+    SetCamera(Camera),
+}
+
+impl<T: BeingType<T>> TickEvent<T> {
+    // the being this event writes to, events without one are applied serially in deterministic mode
+    pub fn get_target(&self) -> Option<ID> {
+        match *self {
+            TickEvent::Sca2(id, _) => Some(id),
+            TickEvent::Sca3(id, _) => Some(id),
+            TickEvent::Rot2(id, _) => Some(id),
+            TickEvent::Rot3(id, _) => Some(id),
+            TickEvent::Pos2(id, _) => Some(id),
+            TickEvent::Pos3(id, _) => Some(id),
+            TickEvent::Vel2(id, _) => Some(id),
+            TickEvent::Vel3(id, _) => Some(id),
+            TickEvent::Acc2(id, _) => Some(id),
+            TickEvent::Acc3(id, _) => Some(id),
+            TickEvent::EntityID(id, _, _) => Some(id),
+            TickEvent::Transform(id, _, _) => Some(id),
+            TickEvent::Message(id, _) => Some(id),
+            _ => None,
+        }
+    }
+
+    // events that change what an automatic model is built from, the target's model is rebuilt after they run
+    pub fn changes_model(&self) -> bool {
+        match *self {
+            TickEvent::Sca2(_, _) | TickEvent::Sca3(_, _) => true,
+            TickEvent::Rot2(_, _) | TickEvent::Rot3(_, _) => true,
+            TickEvent::Pos2(_, _) | TickEvent::Pos3(_, _) => true,
+            TickEvent::EntityID(_, _, _) => true,
+            _ => false,
+        }
+    }
+
+    // events that take ids from the shared manager, deterministic mode runs them serially in target order
+    pub fn allocates_ids(&self) -> bool {
+        match *self {
+            TickEvent::EntityID(_, _, EntityIDEvent::UseNewID(_)) => true,
+            _ => false,
+        }
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match *self {
+            TickEvent::NewBeing(_, _) => "NewBeing",
+            TickEvent::NewBase(_) => "NewBase",
+            TickEvent::Sca2(_, _) => "Sca2",
+            TickEvent::Sca3(_, _) => "Sca3",
+            TickEvent::Rot2(_, _) => "Rot2",
+            TickEvent::Rot3(_, _) => "Rot3",
+            TickEvent::Pos2(_, _) => "Pos2",
+            TickEvent::Pos3(_, _) => "Pos3",
+            TickEvent::Vel2(_, _) => "Vel2",
+            TickEvent::Vel3(_, _) => "Vel3",
+            TickEvent::Acc2(_, _) => "Acc2",
+            TickEvent::Acc3(_, _) => "Acc3",
+            TickEvent::EntityID(_, _, _) => "EntityID",
+            TickEvent::EntityIDBase(_, _, _) => "EntityIDBase",
+            TickEvent::Transform(_, _, _) => "Transform",
+            TickEvent::TransformBase(_, _, _) => "TransformBase",
+            TickEvent::SetPaused(_) => "SetPaused",
+            TickEvent::SetActiveWorld(_) => "SetActiveWorld",
+            TickEvent::Message(_, _) => "Message",
+            TickEvent::Broadcast(_, _) => "Broadcast",
+            TickEvent::Custom(_) => "Custom",
+            TickEvent::Attach(_, _) => "Attach",
+            TickEvent::Detach(_) => "Detach",
+            TickEvent::AttachEntity(_, _, _) => "AttachEntity",
+            TickEvent::DetachEntity(_, _) => "DetachEntity",
+            TickEvent::SetCamera(_) => "SetCamera",
+        }
+    }
 }
 
 #[allow(dead_code)]
@@ -241,8 +580,11 @@ pub enum EntityIDEvent<T: BeingType<T>> {
 pub enum EntityGraphicsEvent {
     Vertices(Vec<Vertex>),
     Indices(Vec<Index>),
-    Texture(&'static [u8]),
+    // borrowed for textures built into the game, owned for ones decoded from a recording
+    Texture(Cow<'static, [u8]>),
     DrawMethod(DrawMethod),
+    // false draws the entity with its own perspective and view instead of the world's camera
+    UseCamera(bool),
 }
 
 #[allow(dead_code)]
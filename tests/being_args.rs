@@ -0,0 +1,54 @@
+#[macro_use]
+extern crate polyclops;
+
+mod common;
+
+use polyclops::{BeingArgs, Property, WorldEvent, TickEvent, Vec3, scene_from_json};
+
+use common::{QuadType};
+
+#[test]
+fn properties_only_read_as_their_own_type() {
+    assert_eq!(Property::Bool(true).as_bool(), Some(true));
+    assert_eq!(Property::Bool(true).as_int(), None);
+    assert_eq!(Property::Int(3).as_int(), Some(3));
+    assert_eq!(Property::Int(3).as_text(), None);
+    assert_eq!(Property::Text("a".to_string()).as_text(), Some("a"));
+    assert_eq!(Property::Text("a".to_string()).as_float(), None);
+    assert_eq!(Property::Vec3(Vec3::one()).as_vec3(), Some(Vec3::one()));
+    assert_eq!(Property::Float(0.5).as_vec3(), None);
+}
+
+#[test]
+fn ints_widen_to_floats() {
+    assert_eq!(Property::Float(0.5).as_float(), Some(0.5));
+    assert_eq!(Property::Int(-2).as_float(), Some(-2.0));
+}
+
+#[test]
+fn later_properties_replace_earlier_ones() {
+    let being_args = BeingArgs::new().with_property("hp", Property::Int(3)).with_property("hp", Property::Float(1.5)).with_tag("red").with_tag("big");
+    assert_eq!(being_args.get_property("hp"), Some(&Property::Float(1.5)));
+    assert_eq!(being_args.get_property("mp"), None);
+    assert!(being_args.has_tag("red"));
+    assert!(being_args.has_tag("big"));
+    assert!(!being_args.has_tag("blue"));
+}
+
+#[test]
+fn scenes_fill_in_tags_texture_and_properties() {
+    let scene = r#"{"beings": [{"type": "Quad", "tags": ["red"], "texture": "TileWood", "properties": {"alive": true, "hp": 3, "speed": 1.5, "title": "x", "home": [1.0, 2.0, 3.0]}}]}"#;
+    let events = scene_from_json::<QuadType>(scene).unwrap();
+    match events[1] {
+        WorldEvent::Tick(TickEvent::NewBeing(QuadType::Quad, ref being_args)) => {
+            assert!(being_args.has_tag("red"));
+            assert_eq!(being_args.texture, Some("TileWood".to_string()));
+            assert_eq!(being_args.get_property("alive").and_then(|property| property.as_bool()), Some(true));
+            assert_eq!(being_args.get_property("hp").and_then(|property| property.as_int()), Some(3));
+            assert_eq!(being_args.get_property("speed").and_then(|property| property.as_float()), Some(1.5));
+            assert_eq!(being_args.get_property("title").and_then(|property| property.as_text()), Some("x"));
+            assert_eq!(being_args.get_property("home").and_then(|property| property.as_vec3()), Some(Vec3::from([1.0, 2.0, 3.0])));
+        },
+        _ => panic!("expected the quad after its base"),
+    }
+}
@@ -0,0 +1,65 @@
+#[macro_use]
+extern crate polyclops;
+
+mod common;
+
+use polyclops::{Game, GameConfig, Headless, HeadlessCall, BeingArgs, ID, Camera, Projection, World, WorldEvent, TickEvent, TickAfterEvent, TransformEvent, EntityGraphicsEvent, Mat4, Vec2, Vec3};
+
+use common::{QuadType, ENTITY_QUAD_ID, new_game, new_base, new_being, being_ids, close};
+
+fn new_quad_game(window: &mut Headless) -> (Game<QuadType>, ID) {
+    let mut game = new_game(GameConfig::new().with_deterministic(true));
+    game.start(vec!(new_base(), new_being(BeingArgs::new())), window);
+    let id = being_ids(&game)[0];
+    (game, id)
+}
+
+fn drawn_camera(game: &mut Game<QuadType>, window: &mut Headless) -> (Mat4, Mat4) {
+    window.clear_calls();
+    game.render(window, 1.0);
+    window.get_calls().iter().filter_map(|call| match *call {
+        HeadlessCall::DrawEntity(_, perspective, view, _) => Some((perspective, view)),
+        _ => None,
+    }).next().unwrap()
+}
+
+#[test]
+fn new_worlds_get_an_orthographic_camera_for_their_resolution() {
+    let world: World<QuadType> = World::new(Vec2::from([128.0, 64.0]));
+    assert_eq!(world.get_camera().projection, Projection::Orthographic);
+    assert_eq!(world.get_camera().aspect_ratio, 2.0);
+    assert_eq!(world.get_camera().zoom, 1.0);
+}
+
+#[test]
+fn entities_are_drawn_through_the_world_camera() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, _) = new_quad_game(&mut window);
+    let camera = Camera::new(1.0).with_projection(Projection::Perspective).with_pos(Vec3::from([1.0, 2.0, 3.0])).with_yaw(30.0);
+    game.start(vec!(WorldEvent::Tick(TickEvent::SetCamera(camera))), &mut window);
+    assert_eq!(drawn_camera(&mut game, &mut window), (camera.get_perspective(), camera.get_view()));
+}
+
+#[test]
+fn zoom_scales_the_picture() {
+    for projection in vec!(Projection::Orthographic, Projection::Perspective) {
+        let camera = Camera::new(1.0).with_projection(projection);
+        let zoomed = camera.with_zoom(2.0);
+        assert!(close(zoomed.get_perspective()[0][0], camera.get_perspective()[0][0] * 2.0));
+        assert!(close(zoomed.get_perspective()[1][1], camera.get_perspective()[1][1] * 2.0));
+    }
+}
+
+#[test]
+fn entities_off_the_camera_keep_their_own_matrices() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, id) = new_quad_game(&mut window);
+    let perspective = Mat4::perspective(0.5, 50.0, 60.0, 1.0);
+    let view = Mat4::view(10.0, 20.0, Vec3::from([0.0, 0.0, 5.0]));
+    game.start(vec!(
+        WorldEvent::Tick(TickEvent::Transform(id, ENTITY_QUAD_ID, TransformEvent::Perspective(perspective, perspective.to_inverse()))),
+        WorldEvent::Tick(TickEvent::Transform(id, ENTITY_QUAD_ID, TransformEvent::View(view, view.to_inverse()))),
+        WorldEvent::TickAfter(TickAfterEvent::Entity(id, ENTITY_QUAD_ID, EntityGraphicsEvent::UseCamera(false))),
+    ), &mut window);
+    assert_eq!(drawn_camera(&mut game, &mut window), (perspective, view));
+}
@@ -0,0 +1,268 @@
+#![allow(dead_code)]
+
+use std::sync::{Arc, RwLock};
+use std::collections::{HashMap};
+use polyclops::{Being, BeingType, BeingArgs, Property, ID, IDManager, IDType, EntityIDType, Entity, World, WorldEvent, TickEvent, TickAfterEvent, EntityIDEvent, EntityGraphicsEvent, Transforms, Vertex, Vec2, Vec3, Vec3Event, DrawMethod, DepthTestMethod, CullingMethod, Game, GameConfig, Encode, Decode, Reader, NamedBeingType, PolyclopsError, init};
+
+pub const QUAD_TEXTURE: &'static [u8] = include_bytes!("../../assets/TileBrick.png");
+
+pub const ENTITY_QUAD_ID: u32 = 0;
+
+// the quad's behaviour is switched on through BeingArgs properties so one being type covers every test
+pub const COUNTS_TICKS: &'static str = "counts_ticks";
+pub const WRITES_TWICE: &'static str = "writes_twice";
+pub const SLEEPS: &'static str = "sleeps";
+pub const MANUAL_MODEL: &'static str = "manual_model";
+pub const NOT_INTEGRATED: &'static str = "not_integrated";
+pub const TICKS_WHILE_PAUSED: &'static str = "ticks_while_paused";
+pub const RESIZES: &'static str = "resizes";
+pub const BROADCASTS_TICKS: &'static str = "broadcasts_ticks";
+
+#[derive(Clone, Hash, Eq, PartialEq, Debug)]
+pub enum QuadType {
+    Quad,
+    Other,
+}
+
+pub struct Quad {
+    being_type: QuadType,
+    entities: HashMap<u32, Arc<RwLock<Entity>>>,
+    id: ID,
+    pos: Vec3,
+    vel: Vec3,
+    acc: Vec3,
+    sca: Vec3,
+    rot: Vec3,
+    args: BeingArgs,
+}
+
+impl Quad {
+    fn new(manager: Arc<IDManager>, being_type: QuadType, base: Option<&Arc<RwLock<Box<Being<QuadType>>>>>, being_args: BeingArgs) -> Quad {
+        let mut entities = HashMap::new();
+        match base {
+            Some(base) => for entry in base.read().expect("Unable to Read Base in New Quad").get_entities() {
+                entities.insert(*entry.0, Arc::new(RwLock::new(Entity::new_from(entry.1))));
+            },
+            None => {
+                entities.insert(ENTITY_QUAD_ID, Arc::new(RwLock::new(Entity::new(manager.clone()))));
+            },
+        }
+        Quad {
+            being_type: being_type,
+            entities: entities,
+            id: ID::new(manager, IDType::Being),
+            pos: being_args.pos.clone().map(|pos| *pos).unwrap_or(Vec3::zero()),
+            vel: being_args.vel.clone().map(|vel| *vel).unwrap_or(Vec3::zero()),
+            acc: being_args.acc.clone().map(|acc| *acc).unwrap_or(Vec3::zero()),
+            sca: being_args.sca.clone().map(|sca| *sca).unwrap_or(Vec3::one()),
+            rot: being_args.rot.clone().map(|rot| *rot).unwrap_or(Vec3::zero()),
+            args: being_args,
+        }
+    }
+
+    fn has(&self, property: &str) -> bool {
+        self.args.properties.get(property).and_then(|property| property.as_bool()).unwrap_or(false)
+    }
+}
+
+impl Being<QuadType> for Quad {
+    fn get_type(&self) -> QuadType {
+        self.being_type.clone()
+    }
+
+    fn get_id(&self) -> ID {
+        self.id
+    }
+
+    fn get_entities(&self) -> &HashMap<u32, Arc<RwLock<Entity>>> {
+        &self.entities
+    }
+
+    // counting ticks through sca x keeps the count visible without any shared state
+    fn tick(&self, world: &World<QuadType>, _: &Transforms, _: &f32) -> Vec<TickEvent<QuadType>> {
+        let mut events = vec!();
+        if self.has(COUNTS_TICKS) {
+            events.push(TickEvent::Sca3(self.id, Vec3Event::Add(Vec3::from([1.0, 0.0, 0.0]))));
+        }
+        // a broadcast has no target, so unlike the count above it doesn't keep a sleeping quad awake
+        if self.has(BROADCASTS_TICKS) {
+            events.push(TickEvent::Broadcast(QuadType::Other, 1));
+        }
+        if self.has(WRITES_TWICE) {
+            events.push(TickEvent::Pos3(self.id, Vec3Event::Set(Vec3::from([1.0, 0.0, 0.0]))));
+            events.push(TickEvent::Pos3(self.id, Vec3Event::Set(Vec3::from([2.0, 0.0, 0.0]))));
+        }
+        for message in world.get_messages(self.id) {
+            events.push(TickEvent::Pos3(self.id, Vec3Event::Add(Vec3::from([*message as f32, 0.0, 0.0]))));
+        }
+        events
+    }
+
+    fn tick_after(&self, _: &World<QuadType>, _: &Transforms) -> Vec<TickAfterEvent<QuadType>> {
+        vec!()
+    }
+
+    fn resize(&self, _: &World<QuadType>, _: Vec2, aspect_ratio: f32) -> Vec<TickEvent<QuadType>> {
+        if self.has(RESIZES) {
+            vec!(TickEvent::Rot3(self.id, Vec3Event::Set(Vec3::from([aspect_ratio, 0.0, 0.0]))))
+        } else {
+            vec!()
+        }
+    }
+
+    fn ticks_while_paused(&self) -> bool {
+        self.has(TICKS_WHILE_PAUSED)
+    }
+
+    fn is_integrated(&self) -> bool {
+        !self.has(NOT_INTEGRATED)
+    }
+
+    fn can_sleep(&self) -> bool {
+        self.has(SLEEPS)
+    }
+
+    fn has_automatic_model(&self) -> bool {
+        !self.has(MANUAL_MODEL)
+    }
+
+    fn get_being_args(&self) -> BeingArgs {
+        let mut being_args = self.args.clone();
+        being_args.pos = None;
+        being_args.vel = None;
+        being_args.acc = None;
+        being_args.sca = None;
+        being_args.rot = None;
+        being_args
+    }
+
+    implement_being!(sca, get_sca3, set_sca3);
+    implement_being!(rot, get_rot3, set_rot3);
+    implement_being!(pos, get_pos3, set_pos3);
+    implement_being!(vel, get_vel3, set_vel3);
+    implement_being!(acc, get_acc3, set_acc3);
+}
+
+impl BeingType<QuadType> for QuadType {
+    // messages move the receiver along x, custom events make that many new quads
+    type Event = u32;
+
+    fn make_being(manager: Arc<IDManager>, being_type: QuadType, world: Arc<RwLock<World<QuadType>>>, being_args: BeingArgs) -> (ID, Vec<WorldEvent<QuadType>>) {
+        let being = {
+            let world = world.read().expect("Unable to Read World in Make Being");
+            Quad::new(manager, being_type.clone(), world.get_base(being_type), being_args)
+        };
+        let id = being.get_id();
+        let events = vec!(WorldEvent::Tick(TickEvent::EntityID(id, ENTITY_QUAD_ID, EntityIDEvent::UseNewID(vec!(EntityIDType::Model)))));
+        world.write().expect("Unable to Write World in Make Being").add_being(Box::new(being));
+        (id, events)
+    }
+
+    fn make_base(manager: Arc<IDManager>, being_type: QuadType, world: Arc<RwLock<World<QuadType>>>) -> Vec<WorldEvent<QuadType>> {
+        let vertices = vec!(
+            Vertex::from(Vec2::from([0.0, 0.0])),
+            Vertex::from(Vec2::from([1.0, 0.0])),
+            Vertex::from(Vec2::from([1.0, 1.0])),
+            Vertex::from(Vec2::from([0.0, 1.0])),
+        );
+        let events = vec!(
+            WorldEvent::TickAfter(TickAfterEvent::EntityBase(being_type.clone(), ENTITY_QUAD_ID, EntityGraphicsEvent::Vertices(vertices))),
+            WorldEvent::TickAfter(TickAfterEvent::EntityBase(being_type.clone(), ENTITY_QUAD_ID, EntityGraphicsEvent::Indices(vec!(0, 1, 2, 2, 3, 0)))),
+            WorldEvent::TickAfter(TickAfterEvent::EntityBase(being_type.clone(), ENTITY_QUAD_ID, EntityGraphicsEvent::Texture(QUAD_TEXTURE.into()))),
+            WorldEvent::TickAfter(TickAfterEvent::EntityBase(being_type.clone(), ENTITY_QUAD_ID, EntityGraphicsEvent::DrawMethod(DrawMethod::Both(DepthTestMethod::IfLess, CullingMethod::Clockwise)))),
+        );
+        world.write().expect("Unable to Write World in Make Base").set_base(being_type.clone(), Box::new(Quad::new(manager, being_type, None, BeingArgs::new())));
+        events
+    }
+
+    fn handle_custom_event(_: Arc<IDManager>, event: u32, _: Arc<RwLock<World<QuadType>>>) -> Vec<WorldEvent<QuadType>> {
+        (0..event).map(|_| WorldEvent::Tick(TickEvent::NewBeing(QuadType::Quad, BeingArgs::new()))).collect()
+    }
+}
+
+impl Encode for QuadType {
+    fn encode(&self, out: &mut Vec<u8>) {
+        match *self {
+            QuadType::Quad => out.push(0),
+            QuadType::Other => out.push(1),
+        }
+    }
+}
+
+impl Decode for QuadType {
+    fn decode(reader: &mut Reader) -> Result<QuadType, PolyclopsError> {
+        match try!(reader.read_u8()) {
+            0 => Ok(QuadType::Quad),
+            1 => Ok(QuadType::Other),
+            tag => Err(PolyclopsError::WorldFormat(format!("unknown quad type {}", tag))),
+        }
+    }
+}
+
+impl NamedBeingType for QuadType {
+    fn get_name(&self) -> &'static str {
+        match *self {
+            QuadType::Quad => "Quad",
+            QuadType::Other => "Other",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<QuadType> {
+        match name {
+            "Quad" => Some(QuadType::Quad),
+            "Other" => Some(QuadType::Other),
+            _ => None,
+        }
+    }
+}
+
+pub fn new_game(config: GameConfig) -> Game<QuadType> {
+    let resolution = Vec2::from([64.0, 64.0]);
+    Game::new(init(), 2, World::new(resolution), resolution, config)
+}
+
+pub fn new_base() -> WorldEvent<QuadType> {
+    WorldEvent::Tick(TickEvent::NewBase(QuadType::Quad))
+}
+
+pub fn new_being(being_args: BeingArgs) -> WorldEvent<QuadType> {
+    WorldEvent::Tick(TickEvent::NewBeing(QuadType::Quad, being_args))
+}
+
+pub fn new_quad(pos: Vec3, vel: Vec3) -> Vec<WorldEvent<QuadType>> {
+    vec!(new_base(), new_being(BeingArgs::new().with_pos(pos).with_vel(vel)))
+}
+
+pub fn with_flag(being_args: BeingArgs, flag: &str) -> BeingArgs {
+    being_args.with_property(flag, Property::Bool(true))
+}
+
+// the being ids of the active world, sorted so the first made comes first
+pub fn being_ids(game: &Game<QuadType>) -> Vec<ID> {
+    let world = game.get_world(game.get_active_world_id()).expect("Unable to Get World in Being IDs").read().expect("Unable to Read World in Being IDs");
+    let mut ids: Vec<ID> = world.get_beings().keys().cloned().collect();
+    ids.sort();
+    ids
+}
+
+pub fn get_pos(game: &Game<QuadType>, id: ID) -> Vec3 {
+    let world = game.get_world(game.get_active_world_id()).expect("Unable to Get World in Get Pos").read().expect("Unable to Read World in Get Pos");
+    let pos = world.get_being(id).expect("Unable to Get Being in Get Pos").read().expect("Unable to Read Being in Get Pos").get_pos3();
+    pos
+}
+
+pub fn get_sca(game: &Game<QuadType>, id: ID) -> Vec3 {
+    let world = game.get_world(game.get_active_world_id()).expect("Unable to Get World in Get Sca").read().expect("Unable to Read World in Get Sca");
+    let sca = world.get_being(id).expect("Unable to Get Being in Get Sca").read().expect("Unable to Read Being in Get Sca").get_sca3();
+    sca
+}
+
+pub fn get_rot(game: &Game<QuadType>, id: ID) -> Vec3 {
+    let world = game.get_world(game.get_active_world_id()).expect("Unable to Get World in Get Rot").read().expect("Unable to Read World in Get Rot");
+    let rot = world.get_being(id).expect("Unable to Get Being in Get Rot").read().expect("Unable to Read Being in Get Rot").get_rot3();
+    rot
+}
+
+pub fn close(a: f32, b: f32) -> bool {
+    (a - b).abs() < 1e-4
+}
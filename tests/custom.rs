@@ -0,0 +1,39 @@
+#[macro_use]
+extern crate polyclops;
+
+mod common;
+
+use polyclops::{Game, GameConfig, Headless, WorldEvent, TickEvent, Vec2};
+
+use common::{QuadType, new_game, new_base, being_ids};
+
+fn custom(count: u32) -> WorldEvent<QuadType> {
+    WorldEvent::Tick(TickEvent::Custom(count))
+}
+
+fn started(config: GameConfig, events: Vec<WorldEvent<QuadType>>, window: &mut Headless) -> Game<QuadType> {
+    let mut game = new_game(config);
+    game.start(events, window);
+    game
+}
+
+#[test]
+fn custom_event_follow_ups_are_executed() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let game = started(GameConfig::new().with_deterministic(true), vec!(new_base(), custom(3)), &mut window);
+    assert_eq!(being_ids(&game).len(), 3);
+}
+
+#[test]
+fn custom_events_run_in_parallel_games_too() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let game = started(GameConfig::new(), vec!(new_base(), custom(2), custom(1)), &mut window);
+    assert_eq!(being_ids(&game).len(), 3);
+}
+
+#[test]
+fn custom_event_without_follow_ups_does_nothing() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let game = started(GameConfig::new().with_deterministic(true), vec!(new_base(), custom(0)), &mut window);
+    assert!(being_ids(&game).is_empty());
+}
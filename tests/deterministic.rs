@@ -0,0 +1,39 @@
+#[macro_use]
+extern crate polyclops;
+
+mod common;
+
+use polyclops::{GameConfig, Headless, BeingArgs, WorldEvent, TickEvent, Vec3Event, Vec2, Vec3};
+
+use common::{WRITES_TWICE, new_game, new_base, new_being, with_flag, being_ids, get_pos};
+
+#[test]
+fn writes_to_one_target_apply_in_emission_order() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let mut game = new_game(GameConfig::new().with_deterministic(true));
+    game.start(vec!(new_base(), new_being(with_flag(BeingArgs::new(), WRITES_TWICE))), &mut window);
+    game.tick_once(&mut window);
+    let id = being_ids(&game)[0];
+    assert_eq!(get_pos(&game, id)[0], 2.0);
+}
+
+#[test]
+fn starting_writes_to_one_target_apply_in_order() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let mut game = new_game(GameConfig::new().with_deterministic(true));
+    game.start(vec!(new_base(), new_being(BeingArgs::new())), &mut window);
+    let id = being_ids(&game)[0];
+    let set = |x: f32| WorldEvent::Tick(TickEvent::Pos3(id, Vec3Event::Set(Vec3::from([x, 0.0, 0.0]))));
+    game.start(vec!(set(1.0), set(2.0), set(3.0)), &mut window);
+    assert_eq!(get_pos(&game, id)[0], 3.0);
+}
+
+#[test]
+fn untargeted_events_run_in_emission_order() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let mut game = new_game(GameConfig::new().with_deterministic(true));
+    game.start(vec!(new_base(), new_being(BeingArgs::new().with_name("first")), new_being(BeingArgs::new().with_name("second")), new_being(BeingArgs::new().with_name("third"))), &mut window);
+    let world = game.get_world(game.get_active_world_id()).unwrap().read().unwrap();
+    let names: Vec<String> = being_ids(&game).iter().map(|id| world.get_being(*id).unwrap().read().unwrap().get_being_args().name.unwrap()).collect();
+    assert_eq!(names, vec!("first".to_string(), "second".to_string(), "third".to_string()));
+}
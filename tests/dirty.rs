@@ -0,0 +1,49 @@
+#[macro_use]
+extern crate polyclops;
+
+mod common;
+
+use std::sync::{Arc};
+
+use polyclops::{GameConfig, Headless, ID, IDType, World, TickEvent, Vec2Event, Vec3Event, Vec2, Vec3, init};
+
+use common::{QuadType, new_game, new_quad, being_ids};
+
+fn new_id() -> ID {
+    ID::new(Arc::new(init()), IDType::Being)
+}
+
+#[test]
+fn taking_the_dirty_set_empties_it() {
+    let world: World<QuadType> = World::new(Vec2::from([64.0, 64.0]));
+    let id = new_id();
+    world.mark_dirty(id);
+    world.mark_dirty(id);
+    let dirty = world.take_dirty();
+    assert_eq!(dirty.len(), 1);
+    assert!(dirty.contains(&id));
+    assert!(world.take_dirty().is_empty());
+}
+
+#[test]
+fn only_transform_events_change_the_model() {
+    let id = new_id();
+    assert!(TickEvent::Pos3::<QuadType>(id, Vec3Event::Add(Vec3::one())).changes_model());
+    assert!(TickEvent::Sca2::<QuadType>(id, Vec2Event::Set(Vec2::zero())).changes_model());
+    assert!(TickEvent::Rot3::<QuadType>(id, Vec3Event::Mul(Vec3::one())).changes_model());
+    assert!(!TickEvent::Vel3::<QuadType>(id, Vec3Event::Add(Vec3::one())).changes_model());
+    assert!(!TickEvent::Message::<QuadType>(id, 1).changes_model());
+    assert!(!TickEvent::Custom::<QuadType>(1).changes_model());
+}
+
+#[test]
+fn each_step_rebuilds_and_clears_the_dirty_set() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let mut game = new_game(GameConfig::new().with_deterministic(true));
+    game.start(new_quad(Vec3::zero(), Vec3::from([1.0, 0.0, 0.0])), &mut window);
+    assert_eq!(being_ids(&game).len(), 1);
+    let world = game.get_world(game.get_active_world_id()).unwrap().clone();
+    assert!(world.read().unwrap().take_dirty().is_empty());
+    game.tick_once(&mut window);
+    assert!(world.read().unwrap().take_dirty().is_empty());
+}
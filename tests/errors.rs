@@ -0,0 +1,35 @@
+#[macro_use]
+extern crate polyclops;
+
+mod common;
+
+use polyclops::{Game, GameConfig, MissingBeingPolicy, World, Software, WorldEvent, TickAfterEvent, EntityGraphicsEvent, Vec2, init};
+
+use common::{QuadType, ENTITY_QUAD_ID, new_base};
+
+fn bad_texture() -> WorldEvent<QuadType> {
+    WorldEvent::TickAfter(TickAfterEvent::EntityBase(QuadType::Quad, ENTITY_QUAD_ID, EntityGraphicsEvent::Texture((&b"not a png"[..]).into())))
+}
+
+fn start_with_bad_texture(policy: MissingBeingPolicy) {
+    let mut window = Software::new(64, 64);
+    let resolution = Vec2::from([64.0, 64.0]);
+    let mut game: Game<QuadType> = Game::new(init(), 2, World::new(resolution), resolution, GameConfig::new().with_missing_being_policy(policy));
+    game.start(vec!(new_base(), bad_texture()), &mut window);
+}
+
+#[test]
+fn dropped_texture_error_does_not_panic() {
+    start_with_bad_texture(MissingBeingPolicy::Drop);
+}
+
+#[test]
+fn logged_texture_error_does_not_panic() {
+    start_with_bad_texture(MissingBeingPolicy::Log);
+}
+
+#[test]
+#[should_panic]
+fn texture_error_panics_under_panic_policy() {
+    start_with_bad_texture(MissingBeingPolicy::Panic);
+}
@@ -0,0 +1,90 @@
+#[macro_use]
+extern crate polyclops;
+
+mod common;
+
+use polyclops::{Game, GameConfig, IntegrationMethod, World, Headless, HeadlessCall, DrawMethod, DepthTestMethod, CullingMethod, Mat4, Vec2, Vec3, init};
+
+use common::{QuadType, QUAD_TEXTURE, ENTITY_QUAD_ID, new_quad};
+
+fn new_game(resolution: Vec2) -> Game<QuadType> {
+    let config = GameConfig::new().with_integration_method(IntegrationMethod::SemiImplicitEuler).with_deterministic(true);
+    Game::new(init(), 2, World::new(resolution), resolution, config)
+}
+
+#[test]
+fn run_for_integrates_velocity() {
+    let resolution = Vec2::from([64.0, 64.0]);
+    let mut window = Headless::new(resolution);
+    let mut game = new_game(resolution);
+    game.start(new_quad(Vec3::zero(), Vec3::from([1.0, 2.0, 0.0])), &mut window);
+    assert!(game.run_for(60, &mut window));
+    let world = game.get_world(game.get_active_world_id()).expect("Unable to Get World in Test").read().expect("Unable to Read World in Test");
+    assert_eq!(world.get_beings().len(), 1);
+    let being = world.get_beings().values().next().expect("Unable to Get Being in Test").read().expect("Unable to Read Being in Test");
+    let pos = being.get_pos3();
+    assert!((pos[0] - 1.0).abs() < 1e-3, "x was {}", pos[0]);
+    assert!((pos[1] - 2.0).abs() < 1e-3, "y was {}", pos[1]);
+    assert_eq!(pos[2], 0.0);
+    assert_eq!(window.get_frame_count(), 60);
+}
+
+#[test]
+fn run_for_draws_every_being_each_frame() {
+    let resolution = Vec2::from([64.0, 64.0]);
+    let mut window = Headless::new(resolution);
+    let mut game = new_game(resolution);
+    game.start(new_quad(Vec3::zero(), Vec3::zero()), &mut window);
+    window.clear_calls();
+    assert!(game.run_for(3, &mut window));
+    let draws = window.get_calls().iter().filter(|call| match **call {
+        HeadlessCall::DrawEntity(_, _, _, _) => true,
+        _ => false,
+    }).count();
+    assert_eq!(draws, 3);
+}
+
+#[test]
+fn run_for_uploads_the_base_then_draws_and_ends_the_frame() {
+    let resolution = Vec2::from([64.0, 64.0]);
+    let mut window = Headless::new(resolution);
+    let mut game = new_game(resolution);
+    window.clear_calls();
+    game.start(new_quad(Vec3::from([0.5, 0.25, 0.0]), Vec3::zero()), &mut window);
+    assert!(game.run_for(1, &mut window));
+    let model_id = {
+        let world = game.get_world(game.get_active_world_id()).expect("Unable to Get World in Test").read().expect("Unable to Read World in Test");
+        let being = world.get_beings().values().next().expect("Unable to Get Being in Test").read().expect("Unable to Read Being in Test");
+        let model_id = being.get_entities()[&ENTITY_QUAD_ID].read().expect("Unable to Read Entity in Test").get_model_id();
+        model_id
+    };
+    let calls = window.get_calls();
+    assert_eq!(calls.len(), 6);
+    match calls[0] {
+        HeadlessCall::Vertices(_, ref vertices) => assert_eq!(vertices.len(), 4),
+        _ => panic!("expected the base's vertices first"),
+    }
+    match calls[1] {
+        HeadlessCall::Indices(_, ref indices) => assert_eq!(*indices, vec!(0, 1, 2, 2, 3, 0)),
+        _ => panic!("expected the base's indices second"),
+    }
+    match calls[2] {
+        HeadlessCall::Texture(_, ref texture) => assert!(&texture[..] == QUAD_TEXTURE),
+        _ => panic!("expected the base's texture third"),
+    }
+    match calls[3] {
+        HeadlessCall::DrawMethod(_, DrawMethod::Both(DepthTestMethod::IfLess, CullingMethod::Clockwise)) => (),
+        _ => panic!("expected the base's draw method fourth"),
+    }
+    match calls[4] {
+        HeadlessCall::DrawEntity(id, _, _, model) => {
+            assert_eq!(id, model_id);
+            assert_eq!(model, Mat4::translation_from_vec3(Vec3::from([0.5, 0.25, 0.0])));
+        },
+        _ => panic!("expected the quad to be drawn after the uploads"),
+    }
+    match calls[5] {
+        HeadlessCall::EndFrame => (),
+        _ => panic!("expected the frame to end last"),
+    }
+}
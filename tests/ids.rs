@@ -0,0 +1,112 @@
+#[macro_use]
+extern crate polyclops;
+
+mod common;
+
+use std::sync::{Arc, RwLock};
+use std::collections::{HashSet};
+use std::thread;
+
+use polyclops::{GameConfig, Headless, HeadlessCall, BeingArgs, Entity, EntityIDType, ENTITY_ID_TYPES, ID, IDType, WorldEvent, TickAfterEvent, Vec2, init};
+
+use common::{QuadType, ENTITY_QUAD_ID, new_game, new_base, new_being, being_ids};
+
+fn end_being(id: ID) -> WorldEvent<QuadType> {
+    WorldEvent::TickAfter(TickAfterEvent::EndBeing(id))
+}
+
+fn get_entity(game: &polyclops::Game<QuadType>, id: ID) -> Arc<RwLock<Entity>> {
+    let world = game.get_world(game.get_active_world_id()).unwrap().read().unwrap();
+    let entity = world.get_being(id).unwrap().read().unwrap().get_entities()[&ENTITY_QUAD_ID].clone();
+    entity
+}
+
+#[test]
+fn freed_id_comes_back_with_the_next_generation() {
+    let manager = Arc::new(init());
+    let first = ID::new(manager.clone(), IDType::Being);
+    manager.free_id(IDType::Being, first);
+    let second = ID::new(manager.clone(), IDType::Being);
+    assert_eq!(second.get_id(), first.get_id());
+    assert_eq!(second.get_generation(), first.get_generation() + 1);
+    assert!(second != first);
+}
+
+#[test]
+fn stale_generation_misses_in_get_being() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let mut game = new_game(GameConfig::new().with_deterministic(true));
+    game.start(vec!(new_base(), new_being(BeingArgs::new())), &mut window);
+    let old = being_ids(&game)[0];
+    game.start(vec!(end_being(old)), &mut window);
+    game.start(vec!(new_being(BeingArgs::new())), &mut window);
+    let new = being_ids(&game)[0];
+    assert_eq!(new.get_id(), old.get_id());
+    assert_eq!(new.get_generation(), old.get_generation() + 1);
+    let world = game.get_world(game.get_active_world_id()).unwrap().read().unwrap();
+    assert!(world.get_being(old).is_none());
+    assert!(world.get_being(new).is_some());
+}
+
+#[test]
+fn end_being_frees_only_unshared_entity_ids() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let mut game = new_game(GameConfig::new().with_deterministic(true));
+    game.start(vec!(new_base(), new_being(BeingArgs::new()), new_being(BeingArgs::new())), &mut window);
+    let ids = being_ids(&game);
+    let ended = get_entity(&game, ids[0]);
+    let kept = get_entity(&game, ids[1]);
+    let model_id = ended.read().unwrap().get_model_id();
+    window.clear_calls();
+    game.start(vec!(end_being(ids[0])), &mut window);
+    // the quads share every id with their base except the model each made for itself
+    let freed: Vec<(EntityIDType, ID)> = window.get_calls().iter().filter_map(|call| match *call {
+        HeadlessCall::FreeBuffer(id_type, id) => Some((id_type, id)),
+        _ => None,
+    }).collect();
+    assert!(freed == vec!((EntityIDType::Model, model_id)));
+    assert_eq!(ended.read().unwrap().get_vertex_id(), kept.read().unwrap().get_vertex_id());
+    game.start(vec!(new_being(BeingArgs::new())), &mut window);
+    let made = being_ids(&game).into_iter().find(|id| *id != ids[1]).unwrap();
+    let made_model_id = get_entity(&game, made).read().unwrap().get_model_id();
+    assert_eq!(made_model_id.get_id(), model_id.get_id());
+    assert_eq!(made_model_id.get_generation(), model_id.get_generation() + 1);
+}
+
+#[test]
+fn every_id_type_has_its_own_counter() {
+    let manager = Arc::new(init());
+    let mut id_types: Vec<IDType> = ENTITY_ID_TYPES.iter().map(|id_type| IDType::Entity(*id_type)).collect();
+    id_types.push(IDType::EntityContainer);
+    id_types.push(IDType::Being);
+    id_types.push(IDType::World);
+    for id_type in id_types {
+        assert_eq!(ID::new(manager.clone(), id_type).get_id(), 1);
+    }
+}
+
+#[test]
+fn threads_sharing_a_manager_get_distinct_ids() {
+    let manager = Arc::new(init());
+    let freed: Vec<ID> = (0..50).map(|_| ID::new(manager.clone(), IDType::Being)).collect();
+    let threads: Vec<thread::JoinHandle<Vec<ID>>> = (0..4).map(|thread| {
+        let manager = manager.clone();
+        let freed: Vec<ID> = freed.iter().cloned().skip(thread).step_by(4).collect();
+        thread::spawn(move || {
+            let mut ids = vec!();
+            for id in freed {
+                manager.free_id(IDType::Being, id);
+                ids.push(ID::new(manager.clone(), IDType::Being));
+                ids.push(ID::new(manager.clone(), IDType::Being));
+            }
+            ids
+        })
+    }).collect();
+    let mut ids = HashSet::new();
+    for thread in threads {
+        for id in thread.join().unwrap() {
+            assert!(ids.insert(id));
+        }
+    }
+    assert_eq!(ids.len(), 100);
+}
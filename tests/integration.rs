@@ -0,0 +1,32 @@
+#[macro_use]
+extern crate polyclops;
+
+mod common;
+
+use polyclops::{GameConfig, Headless, BeingArgs, Vec2, Vec3};
+
+use common::{NOT_INTEGRATED, new_game, new_base, new_being, new_quad, with_flag, being_ids, get_pos, close};
+
+#[test]
+fn velocity_moves_a_being_by_vel_times_dt_in_one_step() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let config = GameConfig::new();
+    let delta_time = config.get_tick_length() as f32;
+    let mut game = new_game(config);
+    game.start(new_quad(Vec3::from([1.0, 0.0, 0.0]), Vec3::from([3.0, -6.0, 0.0])), &mut window);
+    game.tick_once(&mut window);
+    let pos = get_pos(&game, being_ids(&game)[0]);
+    assert!(close(pos[0], 1.0 + 3.0 * delta_time), "x was {}", pos[0]);
+    assert!(close(pos[1], -6.0 * delta_time), "y was {}", pos[1]);
+    assert_eq!(pos[2], 0.0);
+}
+
+#[test]
+fn beings_can_opt_out_of_integration() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let mut game = new_game(GameConfig::new());
+    let being_args = with_flag(BeingArgs::new().with_vel(Vec3::from([3.0, 0.0, 0.0])), NOT_INTEGRATED);
+    game.start(vec!(new_base(), new_being(being_args)), &mut window);
+    game.tick_once(&mut window);
+    assert_eq!(get_pos(&game, being_ids(&game)[0]), Vec3::zero());
+}
@@ -0,0 +1,42 @@
+#[macro_use]
+extern crate polyclops;
+
+mod common;
+
+use polyclops::{Game, GameConfig, Headless, HeadlessCall, Mat4, Vec2, Vec3};
+
+use common::{QuadType, new_game, new_quad};
+
+fn drawn_models(window: &Headless) -> Vec<Mat4> {
+    window.get_calls().iter().filter_map(|call| match *call {
+        HeadlessCall::DrawEntity(_, _, _, model) => Some(model),
+        _ => None,
+    }).collect()
+}
+
+// one second at one unit a second, so the quad moves from x 0 to x 1 over the tick
+fn moved_quad(window: &mut Headless) -> Game<QuadType> {
+    let mut game = new_game(GameConfig::new().with_deterministic(true));
+    game.start(new_quad(Vec3::zero(), Vec3::from([1.0, 0.0, 0.0])), window);
+    game.step(1.0, window, false);
+    window.clear_calls();
+    game
+}
+
+#[test]
+fn render_blends_the_last_two_ticks() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let mut game = moved_quad(&mut window);
+    game.render(&mut window, 0.25);
+    assert_eq!(game.get_interpolation_alpha(), 0.25);
+    assert_eq!(drawn_models(&window), vec!(Mat4::translation_from_vec3(Vec3::from([0.25, 0.0, 0.0]))));
+}
+
+#[test]
+fn alpha_ends_are_the_previous_and_current_ticks() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let mut game = moved_quad(&mut window);
+    game.render(&mut window, 0.0);
+    game.render(&mut window, 1.0);
+    assert_eq!(drawn_models(&window), vec!(Mat4::identity(), Mat4::translation_from_vec3(Vec3::from([1.0, 0.0, 0.0]))));
+}
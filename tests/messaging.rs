@@ -0,0 +1,60 @@
+#[macro_use]
+extern crate polyclops;
+
+mod common;
+
+use polyclops::{Game, GameConfig, Headless, BeingArgs, ID, WorldEvent, TickEvent, PolyclopsError, Vec2};
+
+use common::{QuadType, new_game, new_base, new_being, being_ids, get_pos};
+
+fn message(target: ID, value: u32) -> WorldEvent<QuadType> {
+    WorldEvent::Tick(TickEvent::Message(target, value))
+}
+
+// two quads and an Other, each moving right by whatever it is sent
+fn new_receivers(window: &mut Headless) -> (Game<QuadType>, Vec<ID>, ID) {
+    let mut game = new_game(GameConfig::new().with_deterministic(true));
+    game.start(vec!(new_base(), WorldEvent::Tick(TickEvent::NewBase(QuadType::Other)), new_being(BeingArgs::new()), new_being(BeingArgs::new()), WorldEvent::Tick(TickEvent::NewBeing(QuadType::Other, BeingArgs::new()))), window);
+    let (quads, other) = {
+        let world = game.get_world(game.get_active_world_id()).unwrap().read().unwrap();
+        let (quads, others): (Vec<ID>, Vec<ID>) = being_ids(&game).into_iter().partition(|id| world.get_being(*id).unwrap().read().unwrap().get_type() == QuadType::Quad);
+        (quads, others[0])
+    };
+    (game, quads, other)
+}
+
+#[test]
+fn messages_arrive_on_the_next_tick_only() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, quads, _) = new_receivers(&mut window);
+    game.start(vec!(message(quads[0], 2), message(quads[0], 3)), &mut window);
+    assert_eq!(get_pos(&game, quads[0])[0], 0.0);
+    game.tick_once(&mut window);
+    assert_eq!(get_pos(&game, quads[0])[0], 5.0);
+    assert_eq!(get_pos(&game, quads[1])[0], 0.0);
+    game.tick_once(&mut window);
+    assert_eq!(get_pos(&game, quads[0])[0], 5.0);
+}
+
+#[test]
+fn broadcasts_reach_every_being_of_the_type() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, quads, other) = new_receivers(&mut window);
+    game.start(vec!(WorldEvent::Tick(TickEvent::Broadcast(QuadType::Quad, 4))), &mut window);
+    game.tick_once(&mut window);
+    assert_eq!(get_pos(&game, quads[0])[0], 4.0);
+    assert_eq!(get_pos(&game, quads[1])[0], 4.0);
+    assert_eq!(get_pos(&game, other)[0], 0.0);
+}
+
+#[test]
+fn messages_to_missing_beings_are_errors() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (game, quads, _) = new_receivers(&mut window);
+    let mut world = game.get_world(game.get_active_world_id()).unwrap().write().unwrap();
+    world.del_being(quads[0]);
+    match world.send_message(quads[0], 1) {
+        Err(PolyclopsError::MissingBeing(id)) => assert_eq!(id, quads[0]),
+        _ => panic!("expected a missing being error"),
+    }
+}
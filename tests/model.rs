@@ -0,0 +1,74 @@
+#[macro_use]
+extern crate polyclops;
+
+mod common;
+
+use polyclops::{Game, GameConfig, Headless, HeadlessCall, BeingArgs, ID, ModelOrder, WorldEvent, TickEvent, TransformEvent, Vec3Event, Mat4, Vec2, Vec3, compose_model};
+
+use common::{QuadType, ENTITY_QUAD_ID, MANUAL_MODEL, new_game, new_base, new_being, with_flag, being_ids};
+
+fn scaled_and_moved(x: f32, sca: f32) -> Mat4 {
+    let mut model = Mat4::scalation_from_vec3(Vec3::from([sca, sca, sca]));
+    model[3][0] = x;
+    model
+}
+
+fn new_quad_game(config: GameConfig, being_args: BeingArgs, window: &mut Headless) -> (Game<QuadType>, ID) {
+    let mut game = new_game(config.with_deterministic(true));
+    game.start(vec!(new_base(), new_being(being_args)), window);
+    let id = being_ids(&game)[0];
+    (game, id)
+}
+
+fn drawn_model(game: &mut Game<QuadType>, window: &mut Headless) -> Mat4 {
+    window.clear_calls();
+    game.render(window, 1.0);
+    window.get_calls().iter().filter_map(|call| match *call {
+        HeadlessCall::DrawEntity(_, _, _, model) => Some(model),
+        _ => None,
+    }).next().unwrap()
+}
+
+#[test]
+fn order_decides_whether_scaling_moves_the_translation() {
+    let pos = Vec3::from([1.0, 0.0, 0.0]);
+    let sca = Vec3::from([2.0, 2.0, 2.0]);
+    assert_eq!(compose_model(pos, Vec3::zero(), sca, Vec3::zero(), ModelOrder::TranslationScalationRotation), scaled_and_moved(1.0, 2.0));
+    assert_eq!(compose_model(pos, Vec3::zero(), sca, Vec3::zero(), ModelOrder::ScalationTranslationRotation), scaled_and_moved(2.0, 2.0));
+}
+
+#[test]
+fn pivot_is_moved_to_the_origin_first() {
+    // x' = 2 * (x - 0.5) + 1, so the scaled quad keeps its left edge at the origin
+    let model = compose_model(Vec3::from([1.0, 0.0, 0.0]), Vec3::zero(), Vec3::from([2.0, 2.0, 2.0]), Vec3::from([0.5, 0.0, 0.0]), ModelOrder::TranslationScalationRotation);
+    assert_eq!(model, scaled_and_moved(0.0, 2.0));
+}
+
+#[test]
+fn automatic_model_follows_pos_and_sca() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, id) = new_quad_game(GameConfig::new(), BeingArgs::new().with_pos(Vec3::from([1.0, 0.0, 0.0])), &mut window);
+    assert_eq!(drawn_model(&mut game, &mut window), scaled_and_moved(1.0, 1.0));
+    game.start(vec!(WorldEvent::Tick(TickEvent::Sca3(id, Vec3Event::Set(Vec3::from([2.0, 2.0, 2.0]))))), &mut window);
+    assert_eq!(drawn_model(&mut game, &mut window), scaled_and_moved(1.0, 2.0));
+}
+
+#[test]
+fn configured_order_is_used_when_drawing() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let being_args = BeingArgs::new().with_pos(Vec3::from([1.0, 0.0, 0.0])).with_sca(Vec3::from([2.0, 2.0, 2.0]));
+    let (mut game, _) = new_quad_game(GameConfig::new().with_model_order(ModelOrder::ScalationTranslationRotation), being_args, &mut window);
+    assert_eq!(drawn_model(&mut game, &mut window), scaled_and_moved(2.0, 2.0));
+}
+
+#[test]
+fn manual_model_is_drawn_as_set() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let being_args = with_flag(BeingArgs::new(), MANUAL_MODEL);
+    let (mut game, id) = new_quad_game(GameConfig::new(), being_args, &mut window);
+    let model = scaled_and_moved(3.0, 1.0);
+    game.start(vec!(WorldEvent::Tick(TickEvent::Transform(id, ENTITY_QUAD_ID, TransformEvent::Model(model, model.to_inverse())))), &mut window);
+    assert_eq!(drawn_model(&mut game, &mut window), model);
+    game.start(vec!(WorldEvent::Tick(TickEvent::Pos3(id, Vec3Event::Set(Vec3::from([5.0, 0.0, 0.0]))))), &mut window);
+    assert_eq!(drawn_model(&mut game, &mut window), model);
+}
@@ -0,0 +1,107 @@
+#[macro_use]
+extern crate polyclops;
+
+mod common;
+
+use polyclops::{Game, GameConfig, Headless, HeadlessCall, BeingArgs, ID, WorldEvent, TickEvent, TickAfterEvent, PolyclopsError, Mat4, Vec2, Vec3};
+
+use common::{QuadType, ENTITY_QUAD_ID, new_game, new_base, new_being};
+
+fn named(name: &str, pos: Vec3) -> WorldEvent<QuadType> {
+    new_being(BeingArgs::new().with_name(name).with_pos(pos))
+}
+
+// a parent at x 1, a child at y 2 and a grandchild at z 3, none attached yet
+fn new_family(window: &mut Headless) -> (Game<QuadType>, ID, ID, ID) {
+    let mut game = new_game(GameConfig::new().with_deterministic(true));
+    game.start(vec!(new_base(), named("parent", Vec3::from([1.0, 0.0, 0.0])), named("child", Vec3::from([0.0, 2.0, 0.0])), named("grandchild", Vec3::from([0.0, 0.0, 3.0]))), window);
+    let (parent, child, grandchild) = {
+        let world = game.get_world(game.get_active_world_id()).unwrap().read().unwrap();
+        let find = |name: &str| world.get_beings().iter().find(|entry| entry.1.read().unwrap().get_being_args().name == Some(name.to_string())).map(|entry| *entry.0).unwrap();
+        (find("parent"), find("child"), find("grandchild"))
+    };
+    (game, parent, child, grandchild)
+}
+
+fn attach(game: &mut Game<QuadType>, window: &mut Headless, child: ID, parent: ID) {
+    game.start(vec!(WorldEvent::Tick(TickEvent::Attach(child, parent))), window);
+}
+
+fn drawn_model(game: &mut Game<QuadType>, window: &mut Headless, id: ID) -> Mat4 {
+    let model_id = {
+        let world = game.get_world(game.get_active_world_id()).unwrap().read().unwrap();
+        let model_id = world.get_being(id).unwrap().read().unwrap().get_entities()[&ENTITY_QUAD_ID].read().unwrap().get_model_id();
+        model_id
+    };
+    window.clear_calls();
+    game.render(window, 1.0);
+    window.get_calls().iter().filter_map(|call| match *call {
+        HeadlessCall::DrawEntity(id, _, _, model) if id == model_id => Some(model),
+        _ => None,
+    }).next().unwrap()
+}
+
+#[test]
+fn children_are_drawn_relative_to_every_ancestor() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, parent, child, grandchild) = new_family(&mut window);
+    attach(&mut game, &mut window, child, parent);
+    attach(&mut game, &mut window, grandchild, child);
+    assert_eq!(drawn_model(&mut game, &mut window, parent), Mat4::translation_from_vec3(Vec3::from([1.0, 0.0, 0.0])));
+    assert_eq!(drawn_model(&mut game, &mut window, child), Mat4::translation_from_vec3(Vec3::from([1.0, 2.0, 0.0])));
+    assert_eq!(drawn_model(&mut game, &mut window, grandchild), Mat4::translation_from_vec3(Vec3::from([1.0, 2.0, 3.0])));
+}
+
+#[test]
+fn detached_children_use_their_own_model() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, parent, child, _) = new_family(&mut window);
+    attach(&mut game, &mut window, child, parent);
+    game.start(vec!(WorldEvent::Tick(TickEvent::Detach(child))), &mut window);
+    assert_eq!(drawn_model(&mut game, &mut window, child), Mat4::translation_from_vec3(Vec3::from([0.0, 2.0, 0.0])));
+    assert!(game.get_world(game.get_active_world_id()).unwrap().read().unwrap().get_children(parent).is_empty());
+}
+
+#[test]
+fn ending_a_parent_orphans_its_children() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, parent, child, _) = new_family(&mut window);
+    attach(&mut game, &mut window, child, parent);
+    game.start(vec!(WorldEvent::TickAfter(TickAfterEvent::EndBeing(parent))), &mut window);
+    assert_eq!(game.get_world(game.get_active_world_id()).unwrap().read().unwrap().get_parent(child), None);
+    assert_eq!(drawn_model(&mut game, &mut window, child), Mat4::translation_from_vec3(Vec3::from([0.0, 2.0, 0.0])));
+}
+
+#[test]
+fn cycles_are_refused() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, parent, child, grandchild) = new_family(&mut window);
+    attach(&mut game, &mut window, child, parent);
+    attach(&mut game, &mut window, grandchild, child);
+    let mut world = game.get_world(game.get_active_world_id()).unwrap().write().unwrap();
+    match world.attach(parent, grandchild) {
+        Err(PolyclopsError::InvalidParent(id)) => assert_eq!(id, grandchild),
+        _ => panic!("expected an invalid parent error"),
+    }
+    match world.attach(parent, parent) {
+        Err(PolyclopsError::InvalidParent(_)) => (),
+        _ => panic!("expected an invalid parent error"),
+    }
+    assert_eq!(world.get_parent(parent), None);
+}
+
+#[test]
+fn entity_parents_must_exist_and_not_loop() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (game, parent, _, _) = new_family(&mut window);
+    let mut world = game.get_world(game.get_active_world_id()).unwrap().write().unwrap();
+    match world.attach_entity(parent, ENTITY_QUAD_ID, 5) {
+        Err(PolyclopsError::MissingEntity(5)) => (),
+        _ => panic!("expected a missing entity error"),
+    }
+    match world.attach_entity(parent, ENTITY_QUAD_ID, ENTITY_QUAD_ID) {
+        Err(PolyclopsError::InvalidParent(_)) => (),
+        _ => panic!("expected an invalid parent error"),
+    }
+    assert_eq!(world.get_entity_parent(parent, ENTITY_QUAD_ID), None);
+}
@@ -0,0 +1,84 @@
+#[macro_use]
+extern crate polyclops;
+extern crate glium;
+
+mod common;
+
+use glium::glutin::Event as WindowEvent;
+
+use polyclops::{Game, GameConfig, Headless, BeingArgs, ID, WorldEvent, TickEvent, Vec2, Vec3};
+
+use common::{QuadType, COUNTS_TICKS, TICKS_WHILE_PAUSED, new_game, new_base, new_being, with_flag, being_ids, get_pos, get_sca};
+
+// a moving counter, and a second one that keeps ticking while paused
+fn new_counters(config: GameConfig, window: &mut Headless) -> (Game<QuadType>, ID, ID) {
+    let mut game = new_game(config.with_deterministic(true));
+    let counter = with_flag(BeingArgs::new().with_name("counter").with_vel(Vec3::from([1.0, 0.0, 0.0])), COUNTS_TICKS);
+    let ticker = with_flag(with_flag(BeingArgs::new().with_name("ticker"), COUNTS_TICKS), TICKS_WHILE_PAUSED);
+    game.start(vec!(new_base(), new_being(counter), new_being(ticker)), window);
+    let (counter, ticker) = {
+        let world = game.get_world(game.get_active_world_id()).unwrap().read().unwrap();
+        let find = |name: &str| being_ids(&game).into_iter().find(|id| world.get_being(*id).unwrap().read().unwrap().get_being_args().name == Some(name.to_string())).unwrap();
+        (find("counter"), find("ticker"))
+    };
+    (game, counter, ticker)
+}
+
+#[test]
+fn paused_world_skips_tick_and_integration() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, counter, ticker) = new_counters(GameConfig::new(), &mut window);
+    game.set_paused(true).unwrap();
+    game.step(1.0, &mut window, true);
+    assert_eq!(get_sca(&game, counter)[0], 1.0);
+    assert_eq!(get_pos(&game, counter)[0], 0.0);
+    assert_eq!(get_sca(&game, ticker)[0], 2.0);
+    assert_eq!(window.get_frame_count(), 1);
+    game.set_paused(false).unwrap();
+    game.step(1.0, &mut window, false);
+    assert_eq!(get_sca(&game, counter)[0], 2.0);
+    assert_eq!(get_pos(&game, counter)[0], 1.0);
+}
+
+#[test]
+fn set_paused_event_pauses_the_world() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, _, _) = new_counters(GameConfig::new(), &mut window);
+    game.start(vec!(WorldEvent::Tick(TickEvent::SetPaused(true))), &mut window);
+    assert!(game.is_paused().unwrap());
+}
+
+#[test]
+fn focus_loss_pauses_until_focus_returns() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, counter, _) = new_counters(GameConfig::new(), &mut window);
+    window.push_event(WindowEvent::Focused(false));
+    game.run_for(2, &mut window);
+    assert!(game.is_paused().unwrap());
+    assert_eq!(get_sca(&game, counter)[0], 1.0);
+    window.push_event(WindowEvent::Focused(true));
+    game.run_for(1, &mut window);
+    assert!(!game.is_paused().unwrap());
+    assert_eq!(get_sca(&game, counter)[0], 2.0);
+}
+
+#[test]
+fn focus_does_not_resume_a_manual_pause() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, _, _) = new_counters(GameConfig::new(), &mut window);
+    game.set_paused(true).unwrap();
+    window.push_event(WindowEvent::Focused(false));
+    window.push_event(WindowEvent::Focused(true));
+    game.run_for(1, &mut window);
+    assert!(game.is_paused().unwrap());
+}
+
+#[test]
+fn focus_loss_is_ignored_when_disabled() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, counter, _) = new_counters(GameConfig::new().with_pause_on_focus_loss(false), &mut window);
+    window.push_event(WindowEvent::Focused(false));
+    game.run_for(1, &mut window);
+    assert!(!game.is_paused().unwrap());
+    assert_eq!(get_sca(&game, counter)[0], 2.0);
+}
@@ -0,0 +1,188 @@
+#[macro_use]
+extern crate polyclops;
+extern crate glium;
+
+mod common;
+
+use std::sync::{Arc};
+use glium::glutin::{ElementState, MouseButton, VirtualKeyCode};
+
+use polyclops::{GameConfig, Headless, Record, RecordedInput, Encode, Decode, Reader, PolyclopsError, ID, IDType, EntityIDType, BeingArgs, Property, Camera, Projection, WorldEvent, TickEvent, TickAfterEvent, TransformEvent, EntityGraphicsEvent, EntityIDEvent, Vec2Event, Vec3Event, Vertex, DrawMethod, DepthTestMethod, CullingMethod, Mat4, Vec2, Vec3, init};
+
+use common::{QuadType, QUAD_TEXTURE, new_game, new_quad};
+
+// decoding has to use up exactly the encoded bytes and encode back to the same bytes
+fn round_trip<E: Encode + Decode>(item: &E) {
+    let mut first = vec!();
+    item.encode(&mut first);
+    let mut reader = Reader::new(&first);
+    let decoded = E::decode(&mut reader).expect("Unable to Decode in Round Trip");
+    assert!(reader.is_empty());
+    let mut second = vec!();
+    decoded.encode(&mut second);
+    assert_eq!(first, second);
+}
+
+fn ids() -> (ID, ID) {
+    let manager = Arc::new(init());
+    (ID::new(manager.clone(), IDType::Being), ID::new(manager, IDType::World))
+}
+
+fn tick_events() -> Vec<TickEvent<QuadType>> {
+    let (id, world_id) = ids();
+    let vec2 = Vec2::from([1.0, -2.0]);
+    let vec3 = Vec3::from([1.0, -2.0, 3.5]);
+    let mat4 = Mat4::perspective(0.1, 10.0, 75.0, 1.5);
+    let being_args = BeingArgs::new().with_pos(vec3).with_name("quad").with_property("count", Property::Int(-3)).with_property("label", Property::Text("a".to_string()));
+    vec!(
+        TickEvent::NewBeing(QuadType::Quad, being_args),
+        TickEvent::NewBase(QuadType::Other),
+        TickEvent::Sca2(id, Vec2Event::Set(vec2)),
+        TickEvent::Sca3(id, Vec3Event::Set(vec3)),
+        TickEvent::Rot2(id, Vec2Event::Add(vec2)),
+        TickEvent::Rot3(id, Vec3Event::Add(vec3)),
+        TickEvent::Pos2(id, Vec2Event::Mul(vec2)),
+        TickEvent::Pos3(id, Vec3Event::Mul(vec3)),
+        TickEvent::Vel2(id, Vec2Event::Set(vec2)),
+        TickEvent::Vel3(id, Vec3Event::Add(vec3)),
+        TickEvent::Acc2(id, Vec2Event::Add(vec2)),
+        TickEvent::Acc3(id, Vec3Event::Mul(vec3)),
+        TickEvent::EntityID(id, 1, EntityIDEvent::UseNewID(vec!(EntityIDType::Vertex, EntityIDType::Model))),
+        TickEvent::EntityID(id, 2, EntityIDEvent::UseOldID(id, 3, vec!(EntityIDType::Texture))),
+        TickEvent::EntityIDBase(QuadType::Quad, 4, EntityIDEvent::UseBaseID(QuadType::Other, 5, vec!(EntityIDType::Index, EntityIDType::DrawParameter, EntityIDType::Perspective, EntityIDType::View))),
+        TickEvent::Transform(id, 0, TransformEvent::Perspective(mat4, mat4.to_inverse())),
+        TickEvent::Transform(id, 0, TransformEvent::View(mat4, mat4.to_inverse())),
+        TickEvent::TransformBase(QuadType::Quad, 0, TransformEvent::Model(mat4, mat4.to_inverse())),
+        TickEvent::SetPaused(true),
+        TickEvent::SetActiveWorld(world_id),
+        TickEvent::Message(id, 7),
+        TickEvent::Broadcast(QuadType::Quad, 8),
+        TickEvent::Custom(9),
+        TickEvent::Attach(id, id),
+        TickEvent::Detach(id),
+        TickEvent::AttachEntity(id, 1, 0),
+        TickEvent::DetachEntity(id, 1),
+        TickEvent::SetCamera(Camera::new(1.5)),
+    )
+}
+
+fn tick_after_events() -> Vec<TickAfterEvent<QuadType>> {
+    let (id, _) = ids();
+    let vertices = vec!(Vertex::from(Vec2::from([0.0, 0.0])), Vertex::from(Vec2::from([1.0, 0.5])));
+    vec!(
+        TickAfterEvent::Entity(id, 0, EntityGraphicsEvent::Vertices(vertices)),
+        TickAfterEvent::Entity(id, 0, EntityGraphicsEvent::Indices(vec!(0, 1, 2))),
+        TickAfterEvent::Entity(id, 0, EntityGraphicsEvent::Texture(QUAD_TEXTURE.into())),
+        TickAfterEvent::Entity(id, 0, EntityGraphicsEvent::UseCamera(false)),
+        TickAfterEvent::EntityBase(QuadType::Quad, 0, EntityGraphicsEvent::DrawMethod(DrawMethod::Both(DepthTestMethod::IfLess, CullingMethod::Clockwise))),
+        TickAfterEvent::EntityBase(QuadType::Quad, 0, EntityGraphicsEvent::DrawMethod(DrawMethod::Depth(DepthTestMethod::IfLess))),
+        TickAfterEvent::EntityBase(QuadType::Quad, 0, EntityGraphicsEvent::DrawMethod(DrawMethod::Culling(CullingMethod::CounterClockwise))),
+        TickAfterEvent::EndBeing(id),
+    )
+}
+
+fn inputs() -> Vec<RecordedInput> {
+    let (_, world_id) = ids();
+    vec!(
+        RecordedInput::Keyboard(VirtualKeyCode::A, ElementState::Pressed),
+        RecordedInput::MouseButton(MouseButton::Left, ElementState::Released),
+        RecordedInput::MousePos(-4, 12),
+        RecordedInput::Resolution(640, 480),
+        RecordedInput::Paused(false),
+        RecordedInput::ActiveWorld(world_id),
+    )
+}
+
+#[test]
+fn every_tick_event_round_trips() {
+    for event in tick_events() {
+        round_trip(&event);
+    }
+}
+
+#[test]
+fn every_tick_after_event_round_trips() {
+    for event in tick_after_events() {
+        round_trip(&event);
+    }
+}
+
+#[test]
+fn every_world_event_round_trips() {
+    round_trip(&WorldEvent::Tick(TickEvent::NewBase(QuadType::Quad)));
+    round_trip(&WorldEvent::TickAfter::<QuadType>(TickAfterEvent::EndBeing(ids().0)));
+}
+
+#[test]
+fn every_record_round_trips() {
+    let (_, world_id) = ids();
+    let mut records: Vec<Record<QuadType>> = vec!(
+        Record::Start(world_id, vec!(WorldEvent::Tick(TickEvent::NewBase(QuadType::Quad)), WorldEvent::TickAfter(TickAfterEvent::EndBeing(ids().0)))),
+        Record::Tick(world_id, 0.25, tick_events()),
+        Record::TickAfter(world_id, tick_after_events()),
+        Record::EndStep,
+    );
+    for input in inputs() {
+        records.push(Record::Input(input));
+    }
+    for record in records.iter() {
+        round_trip(record);
+    }
+}
+
+#[test]
+fn camera_round_trips() {
+    let mut camera = Camera::new(2.0);
+    camera.projection = Projection::Perspective;
+    round_trip(&camera);
+}
+
+#[test]
+fn truncated_record_is_an_error() {
+    let (_, world_id) = ids();
+    let record: Record<QuadType> = Record::Tick(world_id, 0.25, tick_events());
+    let mut data = vec!();
+    record.encode(&mut data);
+    for length in 0..data.len() {
+        let mut reader = Reader::new(&data[..length]);
+        assert!(Record::<QuadType>::decode(&mut reader).is_err());
+    }
+}
+
+#[test]
+fn truncated_recording_is_an_error() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let mut game = new_game(GameConfig::new().with_deterministic(true));
+    game.start_recording().unwrap();
+    game.start(new_quad(Vec3::zero(), Vec3::zero()), &mut window);
+    game.tick_once(&mut window);
+    let data = game.stop_recording().unwrap();
+    // the last record is the one byte EndStep, so cutting two bytes leaves the record before it unfinished
+    let mut replay = new_game(GameConfig::new().with_deterministic(true));
+    match replay.replay(&data[..data.len() - 2], &mut window, false) {
+        Err(PolyclopsError::Decode(_)) => (),
+        _ => panic!("expected a decode error"),
+    }
+}
+
+#[test]
+fn bad_magic_is_an_error() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let mut game = new_game(GameConfig::new().with_deterministic(true));
+    match game.replay(b"NOPE\x02", &mut window, false) {
+        Err(PolyclopsError::Decode(_)) => (),
+        _ => panic!("expected a decode error"),
+    }
+}
+
+#[test]
+fn recording_needs_a_deterministic_game() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let mut game = new_game(GameConfig::new());
+    assert!(game.start_recording().is_err());
+    assert!(!game.is_recording());
+    match game.replay(b"PCLR\x02", &mut window, false) {
+        Err(PolyclopsError::Recording(_)) => (),
+        _ => panic!("expected a recording error"),
+    }
+}
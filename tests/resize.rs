@@ -0,0 +1,57 @@
+#[macro_use]
+extern crate polyclops;
+extern crate glium;
+
+mod common;
+
+use glium::glutin::Event as WindowEvent;
+
+use polyclops::{Game, GameConfig, Headless, Renderer, BeingArgs, ID, World, Vec2};
+
+use common::{QuadType, RESIZES, new_game, new_base, new_being, with_flag, being_ids, get_rot};
+
+// a quad that keeps the aspect ratio in its rot x, plus a second world
+fn new_resizer(window: &mut Headless) -> (Game<QuadType>, ID, ID) {
+    let mut game = new_game(GameConfig::new().with_deterministic(true));
+    game.start(vec!(new_base(), new_being(with_flag(BeingArgs::new(), RESIZES))), window);
+    let id = being_ids(&game)[0];
+    let other = game.add_world(World::new(Vec2::from([64.0, 64.0])));
+    (game, id, other)
+}
+
+fn resize(game: &mut Game<QuadType>, window: &mut Headless, width: u32, height: u32) {
+    window.push_event(WindowEvent::Resized(width, height));
+    assert!(game.poll_events(window));
+}
+
+#[test]
+fn resize_updates_every_world_camera() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, _, other) = new_resizer(&mut window);
+    resize(&mut game, &mut window, 128, 64);
+    assert_eq!(window.get_resolution_vec2(), Vec2::from([128.0, 64.0]));
+    for world_id in vec!(game.get_active_world_id(), other) {
+        let world = game.get_world(world_id).unwrap().read().unwrap();
+        assert_eq!(world.get_resolution(), Vec2::from([128.0, 64.0]));
+        assert_eq!(world.get_aspect_ratio(), 2.0);
+        assert_eq!(world.get_camera().aspect_ratio, 2.0);
+    }
+}
+
+#[test]
+fn beings_hear_about_the_new_aspect_ratio() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, id, _) = new_resizer(&mut window);
+    assert_eq!(get_rot(&game, id)[0], 0.0);
+    resize(&mut game, &mut window, 64, 128);
+    assert_eq!(get_rot(&game, id)[0], 0.5);
+}
+
+#[test]
+fn worlds_added_after_a_resize_use_the_new_resolution() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, _, _) = new_resizer(&mut window);
+    resize(&mut game, &mut window, 128, 64);
+    let added = game.add_world(World::new(Vec2::from([64.0, 64.0])));
+    assert_eq!(game.get_world(added).unwrap().read().unwrap().get_camera().aspect_ratio, 2.0);
+}
@@ -0,0 +1,56 @@
+#[macro_use]
+extern crate polyclops;
+
+mod common;
+
+use polyclops::{GameConfig, Headless, BeingArgs, Property, PolyclopsError, Vec2, Vec3, world_from_json};
+
+use common::{QuadType, new_game, new_base, new_being, being_ids, get_pos};
+
+#[test]
+fn saved_world_loads_back() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let mut game = new_game(GameConfig::new());
+    let being_args = BeingArgs::new().with_pos(Vec3::from([1.0, 2.0, 3.0])).with_name("saved").with_property("count", Property::Int(7));
+    game.start(vec!(new_base(), new_being(being_args)), &mut window);
+    let data = game.save_world(game.get_active_world_id()).unwrap();
+    let world_id = game.load_world(&data, &mut window).unwrap();
+    game.set_active_world(world_id).unwrap();
+    let ids = being_ids(&game);
+    assert_eq!(ids.len(), 1);
+    assert_eq!(get_pos(&game, ids[0]), Vec3::from([1.0, 2.0, 3.0]));
+    let world = game.get_world(world_id).unwrap().read().unwrap();
+    let being_args = world.get_being(ids[0]).unwrap().read().unwrap().get_being_args();
+    assert_eq!(being_args.name, Some("saved".to_string()));
+    assert_eq!(being_args.properties.get("count").and_then(|property| property.as_int()), Some(7));
+    assert_eq!(game.save_world(world_id).unwrap(), data);
+}
+
+#[test]
+fn missing_paused_fields_default_to_false() {
+    let saved = world_from_json::<QuadType>(r#"{"version": 3, "bases": [], "beings": []}"#).unwrap();
+    assert!(!saved.paused);
+    assert!(!saved.tick_in_background);
+}
+
+#[test]
+fn out_of_range_int_is_a_format_error() {
+    let data = r#"{"version": 3, "bases": [], "beings": [{"type": "Quad", "properties": {"count": 4294967296}, "entities": []}]}"#;
+    match world_from_json::<QuadType>(data) {
+        Err(PolyclopsError::WorldFormat(_)) => (),
+        _ => panic!("expected a world format error"),
+    }
+}
+
+#[test]
+fn loading_while_recording_is_refused() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let mut game = new_game(GameConfig::new().with_deterministic(true));
+    game.start(vec!(new_base(), new_being(BeingArgs::new())), &mut window);
+    let data = game.save_world(game.get_active_world_id()).unwrap();
+    game.start_recording().unwrap();
+    match game.load_world(&data, &mut window) {
+        Err(PolyclopsError::Recording(_)) => (),
+        _ => panic!("expected a recording error"),
+    }
+}
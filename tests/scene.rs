@@ -0,0 +1,73 @@
+#[macro_use]
+extern crate polyclops;
+
+mod common;
+
+use polyclops::{PolyclopsError, WorldEvent, TickEvent, Vec3, scene_from_json};
+
+use common::{QuadType};
+
+const SCENE: &'static str = r#"{
+    "beings": [
+        {"type": "Quad", "name": "first", "pos": [1.0, 2.0, 3.0]},
+        {"type": "Other", "texture": "TileWood"},
+        {"type": "Quad"}
+    ]
+}"#;
+
+fn is_format_error(data: &str) -> bool {
+    match scene_from_json::<QuadType>(data) {
+        Err(PolyclopsError::WorldFormat(_)) => true,
+        _ => false,
+    }
+}
+
+#[test]
+fn scene_makes_each_base_once_before_the_beings() {
+    let events = scene_from_json::<QuadType>(SCENE).unwrap();
+    assert_eq!(events.len(), 5);
+    match events[0] {
+        WorldEvent::Tick(TickEvent::NewBase(QuadType::Quad)) => (),
+        _ => panic!("expected the quad base first"),
+    }
+    match events[1] {
+        WorldEvent::Tick(TickEvent::NewBase(QuadType::Other)) => (),
+        _ => panic!("expected the other base second"),
+    }
+    match events[2] {
+        WorldEvent::Tick(TickEvent::NewBeing(QuadType::Quad, ref being_args)) => {
+            assert_eq!(being_args.name, Some("first".to_string()));
+            assert_eq!(being_args.pos.clone().map(|pos| *pos), Some(Vec3::from([1.0, 2.0, 3.0])));
+        },
+        _ => panic!("expected the first quad after the bases"),
+    }
+    match events[3] {
+        WorldEvent::Tick(TickEvent::NewBeing(QuadType::Other, ref being_args)) => assert_eq!(being_args.texture, Some("TileWood".to_string())),
+        _ => panic!("expected the other being second"),
+    }
+    match events[4] {
+        WorldEvent::Tick(TickEvent::NewBeing(QuadType::Quad, _)) => (),
+        _ => panic!("expected the last quad last"),
+    }
+}
+
+#[test]
+fn listed_bases_replace_the_ones_found_in_beings() {
+    let events = scene_from_json::<QuadType>(r#"{"bases": ["Other"], "beings": [{"type": "Quad"}]}"#).unwrap();
+    assert_eq!(events.len(), 2);
+    match events[0] {
+        WorldEvent::Tick(TickEvent::NewBase(QuadType::Other)) => (),
+        _ => panic!("expected only the listed base"),
+    }
+}
+
+#[test]
+fn malformed_scenes_are_format_errors() {
+    assert!(is_format_error("{"));
+    assert!(is_format_error("{}"));
+    assert!(is_format_error(r#"{"beings": {}}"#));
+    assert!(is_format_error(r#"{"beings": [{"pos": [0.0, 0.0, 0.0]}]}"#));
+    assert!(is_format_error(r#"{"beings": [{"type": "Nope"}]}"#));
+    assert!(is_format_error(r#"{"beings": [{"type": "Quad", "pos": [0.0, 0.0]}]}"#));
+    assert!(is_format_error(r#"{"beings": [{"type": "Quad", "name": 3}]}"#));
+}
@@ -0,0 +1,70 @@
+#[macro_use]
+extern crate polyclops;
+
+mod common;
+
+use polyclops::{Game, GameConfig, Headless, BeingArgs, ID, WorldEvent, TickEvent, Vec3Event, Vec2, Vec3};
+
+use common::{QuadType, SLEEPS, BROADCASTS_TICKS, new_game, new_base, new_being, with_flag, get_pos};
+
+// a sleeping quad that broadcasts every tick it runs, and an Other that counts the broadcasts in its pos x
+fn new_sleeper(window: &mut Headless) -> (Game<QuadType>, ID, ID) {
+    let mut game = new_game(GameConfig::new().with_deterministic(true));
+    let sleeper = with_flag(with_flag(BeingArgs::new().with_name("sleeper"), SLEEPS), BROADCASTS_TICKS);
+    game.start(vec!(new_base(), WorldEvent::Tick(TickEvent::NewBase(QuadType::Other)), new_being(sleeper), WorldEvent::Tick(TickEvent::NewBeing(QuadType::Other, BeingArgs::new()))), window);
+    let (sleeper, counter) = {
+        let world = game.get_world(game.get_active_world_id()).unwrap().read().unwrap();
+        let sleeper = world.get_beings().iter().find(|entry| entry.1.read().unwrap().get_type() == QuadType::Quad).map(|entry| *entry.0).unwrap();
+        let counter = world.get_beings().iter().find(|entry| entry.1.read().unwrap().get_type() == QuadType::Other).map(|entry| *entry.0).unwrap();
+        (sleeper, counter)
+    };
+    (game, sleeper, counter)
+}
+
+fn tick(game: &mut Game<QuadType>, window: &mut Headless, ticks: u32) {
+    for _ in 0..ticks {
+        game.tick_once(window);
+    }
+}
+
+fn is_sleeping(game: &Game<QuadType>, id: ID) -> bool {
+    game.get_world(game.get_active_world_id()).unwrap().read().unwrap().is_sleeping(id)
+}
+
+#[test]
+fn sleeping_being_is_not_ticked() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, sleeper, counter) = new_sleeper(&mut window);
+    tick(&mut game, &mut window, 5);
+    assert!(is_sleeping(&game, sleeper));
+    let count = get_pos(&game, counter)[0];
+    assert!(count > 0.0);
+    tick(&mut game, &mut window, 10);
+    assert_eq!(get_pos(&game, counter)[0], count);
+}
+
+#[test]
+fn message_wakes_a_sleeping_being() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, sleeper, counter) = new_sleeper(&mut window);
+    tick(&mut game, &mut window, 5);
+    let count = get_pos(&game, counter)[0];
+    game.start(vec!(WorldEvent::Tick(TickEvent::Message(sleeper, 0))), &mut window);
+    assert!(!is_sleeping(&game, sleeper));
+    tick(&mut game, &mut window, 5);
+    assert!(get_pos(&game, counter)[0] > count);
+    assert!(is_sleeping(&game, sleeper));
+}
+
+#[test]
+fn event_wakes_a_sleeping_being() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, sleeper, counter) = new_sleeper(&mut window);
+    tick(&mut game, &mut window, 5);
+    let count = get_pos(&game, counter)[0];
+    game.start(vec!(WorldEvent::Tick(TickEvent::Pos3(sleeper, Vec3Event::Set(Vec3::from([4.0, 0.0, 0.0]))))), &mut window);
+    assert!(!is_sleeping(&game, sleeper));
+    tick(&mut game, &mut window, 5);
+    assert!(get_pos(&game, counter)[0] > count);
+    assert_eq!(get_pos(&game, sleeper)[0], 4.0);
+}
@@ -0,0 +1,95 @@
+#[macro_use]
+extern crate polyclops;
+extern crate image;
+
+mod common;
+
+use std::env;
+use std::path::{Path};
+
+use std::f32::consts::{PI};
+use image::{Rgba, RgbaImage};
+
+use polyclops::{Game, GameConfig, World, Software, Renderer, Camera, Projection, BeingArgs, WorldEvent, TickEvent, Vec2, Vec3, init};
+
+use common::{QuadType, QUAD_TEXTURE, new_quad, new_base, new_being};
+
+const CLEAR: Rgba<u8> = Rgba { data: [0, 0, 0, 255] };
+
+fn render_quad(being_args: BeingArgs, camera: Camera) -> Software {
+    let mut window = Software::new(64, 64);
+    let resolution = Vec2::from([64.0, 64.0]);
+    let mut game: Game<QuadType> = Game::new(init(), 2, World::new(resolution), resolution, GameConfig::new().with_deterministic(true));
+    game.start(vec!(WorldEvent::Tick(TickEvent::SetCamera(camera)), new_base(), new_being(being_args)), &mut window);
+    game.render(&mut window, 1.0);
+    window
+}
+
+fn lit_pixels(image: &RgbaImage) -> usize {
+    image.pixels().filter(|pixel| **pixel != CLEAR).count()
+}
+
+const GOLDEN_QUAD: &'static str = "tests/golden/quad.png";
+
+// POLYCLOPS_BLESS=1 rewrites the golden image from the current output instead of comparing against it
+#[test]
+fn software_matches_golden_quad() {
+    let mut window = Software::new(64, 64);
+    let resolution = Vec2::from([64.0, 64.0]);
+    let mut game: Game<QuadType> = Game::new(init(), 2, World::new(resolution), resolution, GameConfig::new().with_deterministic(true));
+    game.start(new_quad(Vec3::from([-0.5, -0.5, -1.0]), Vec3::zero()), &mut window);
+    game.render(&mut window, 1.0);
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(GOLDEN_QUAD);
+    if env::var("POLYCLOPS_BLESS").is_ok() {
+        window.get_image().save(&path).expect("Unable to Save Golden Image");
+        return;
+    }
+    let golden = image::open(&path).expect("Unable to Open Golden Image").to_rgba();
+    let image = window.get_image();
+    assert_eq!(golden.dimensions(), image.dimensions());
+    let different = golden.pixels().zip(image.pixels()).filter(|pixels| pixels.0 != pixels.1).count();
+    assert_eq!(different, 0, "{} pixels differ from {}", different, GOLDEN_QUAD);
+}
+
+// the default camera is orthographic with a 75 degree field of view, and with this Mat4 layout its w grows with depth,
+// so a quad at z = -1 has w = 1 + (far + near) / (far - near) and each world unit covers d / w of the half screen
+#[test]
+fn software_pixels_match_the_hand_derived_quad() {
+    let window = render_quad(BeingArgs::new().with_pos(Vec3::from([-0.5, -0.5, -1.0])), Camera::new(1.0));
+    let image = window.get_image();
+    let texture = image::load_from_memory(QUAD_TEXTURE).expect("Unable to Load Quad Texture").to_rgba();
+    let d = 1.0 / (75.0f32 * PI / 360.0).tan();
+    let w = 1.0 + 100.1 / 99.9;
+    let scale = d / w;
+    // pixel centres map back to the quad's tex coords, which are its local x and y
+    let texel = |x: u32, y: u32| {
+        let u = ((x as f32 + 0.5) / 32.0 - 1.0) / scale + 0.5;
+        let v = (1.0 - (y as f32 + 0.5) / 32.0) / scale + 0.5;
+        let (width, height) = texture.dimensions();
+        *texture.get_pixel((u * width as f32) as u32, ((1.0 - v) * height as f32) as u32)
+    };
+    // the quad spans 32 * (1 +- scale / 2), about 21.6 to 42.4, so pixels 22 to 41 are covered
+    assert!(*image.get_pixel(32, 32) == texel(32, 32));
+    assert!(*image.get_pixel(22, 22) == texel(22, 22));
+    assert!(*image.get_pixel(41, 41) == texel(41, 41));
+    assert!(*image.get_pixel(0, 0) == CLEAR);
+    assert!(*image.get_pixel(21, 32) == CLEAR);
+    assert!(*image.get_pixel(42, 32) == CLEAR);
+    assert!(*image.get_pixel(32, 63) == CLEAR);
+    assert_eq!(lit_pixels(image), 20 * 20);
+}
+
+// a floor running from in front of the camera to behind it is cut at the near plane instead of dropped
+#[test]
+fn software_clips_triangles_crossing_the_near_plane() {
+    let being_args = BeingArgs::new().with_pos(Vec3::from([-0.5, -0.5, -0.5])).with_rot(Vec3::from([-PI / 2.0, 0.0, 0.0]));
+    let window = render_quad(being_args, Camera::new(1.0).with_projection(Projection::Perspective));
+    assert!(lit_pixels(window.get_image()) > 0);
+}
+
+#[test]
+fn software_resize_reallocates_the_image() {
+    let mut window = Software::new(64, 64);
+    window.set_resolution((128, 32));
+    assert_eq!(window.get_image().dimensions(), (128, 32));
+}
@@ -0,0 +1,59 @@
+#[macro_use]
+extern crate polyclops;
+extern crate glium;
+
+mod common;
+
+use glium::glutin::Event as WindowEvent;
+
+use polyclops::{Game, GameConfig, Headless, BeingArgs, ID, Vec2, Vec3};
+
+use common::{QuadType, COUNTS_TICKS, new_game, new_base, new_being, with_flag, being_ids, get_pos, get_sca, close};
+
+fn new_counter(window: &mut Headless) -> (Game<QuadType>, ID) {
+    let mut game = new_game(GameConfig::new().with_deterministic(true));
+    let being_args = with_flag(BeingArgs::new(), COUNTS_TICKS).with_vel(Vec3::from([1.0, 0.0, 0.0]));
+    game.start(vec!(new_base(), new_being(being_args)), window);
+    let id = being_ids(&game)[0];
+    (game, id)
+}
+
+#[test]
+fn tick_once_ticks_without_drawing() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, id) = new_counter(&mut window);
+    game.tick_once(&mut window);
+    game.tick_once(&mut window);
+    assert_eq!(get_sca(&game, id)[0], 3.0);
+    assert_eq!(window.get_frame_count(), 0);
+}
+
+#[test]
+fn step_uses_the_given_delta_time() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, id) = new_counter(&mut window);
+    game.step(0.5, &mut window, false);
+    assert!(close(get_pos(&game, id)[0], 0.5));
+    game.step(0.25, &mut window, true);
+    assert!(close(get_pos(&game, id)[0], 0.75));
+    assert_eq!(window.get_frame_count(), 1);
+}
+
+#[test]
+fn run_for_ticks_and_draws_each_step() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, id) = new_counter(&mut window);
+    assert!(game.run_for(4, &mut window));
+    assert_eq!(get_sca(&game, id)[0], 5.0);
+    assert_eq!(window.get_frame_count(), 4);
+}
+
+#[test]
+fn run_for_stops_when_the_window_closes() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, id) = new_counter(&mut window);
+    window.push_event(WindowEvent::Closed);
+    assert!(!game.run_for(4, &mut window));
+    assert_eq!(get_sca(&game, id)[0], 1.0);
+    assert_eq!(window.get_frame_count(), 0);
+}
@@ -0,0 +1,55 @@
+#[macro_use]
+extern crate polyclops;
+
+mod common;
+
+use polyclops::{Game, GameConfig, DropTimePolicy, Headless, BeingArgs, ID, Vec2};
+
+use common::{QuadType, COUNTS_TICKS, new_game, new_base, new_being, with_flag, being_ids, get_sca, close};
+
+// ten ticks a second, with a quad whose sca x goes up by one every tick
+fn new_counter(config: GameConfig, window: &mut Headless) -> (Game<QuadType>, ID) {
+    let mut game = new_game(config.with_tick_rate(10.0).with_deterministic(true));
+    game.start(vec!(new_base(), new_being(with_flag(BeingArgs::new(), COUNTS_TICKS))), window);
+    let id = being_ids(&game)[0];
+    (game, id)
+}
+
+fn ticks_counted(game: &Game<QuadType>, id: ID) -> f32 {
+    get_sca(game, id)[0] - 1.0
+}
+
+#[test]
+fn tick_length_follows_the_tick_rate() {
+    assert!(close(GameConfig::new().with_tick_rate(20.0).get_tick_length() as f32, 0.05));
+}
+
+#[test]
+fn elapsed_time_runs_whole_ticks_and_keeps_the_rest() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, id) = new_counter(GameConfig::new(), &mut window);
+    assert_eq!(game.advance(0.35, &mut window), 3);
+    assert_eq!(ticks_counted(&game, id), 3.0);
+    assert_eq!(game.advance(0.06, &mut window), 1);
+    assert_eq!(ticks_counted(&game, id), 4.0);
+}
+
+#[test]
+fn drop_forgets_time_past_the_catch_up_limit() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, id) = new_counter(GameConfig::new().with_max_catch_up_ticks(2).with_drop_time_policy(DropTimePolicy::Drop), &mut window);
+    assert_eq!(game.advance(1.05, &mut window), 2);
+    assert_eq!(game.advance(0.0, &mut window), 0);
+    assert_eq!(ticks_counted(&game, id), 2.0);
+}
+
+#[test]
+fn carry_catches_up_on_later_frames() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, id) = new_counter(GameConfig::new().with_max_catch_up_ticks(2).with_drop_time_policy(DropTimePolicy::Carry), &mut window);
+    assert_eq!(game.advance(0.55, &mut window), 2);
+    assert_eq!(game.advance(0.0, &mut window), 2);
+    assert_eq!(game.advance(0.0, &mut window), 1);
+    assert_eq!(game.advance(0.0, &mut window), 0);
+    assert_eq!(ticks_counted(&game, id), 5.0);
+}
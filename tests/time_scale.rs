@@ -0,0 +1,61 @@
+#[macro_use]
+extern crate polyclops;
+
+mod common;
+
+use polyclops::{Game, GameConfig, Headless, ID, Vec2, Vec3};
+
+use common::{QuadType, new_game, new_quad, being_ids, get_pos, close};
+
+// a quad moving one unit a second
+fn new_mover(config: GameConfig, window: &mut Headless) -> (Game<QuadType>, ID) {
+    let mut game = new_game(config.with_deterministic(true));
+    game.start(new_quad(Vec3::zero(), Vec3::from([1.0, 0.0, 0.0])), window);
+    let id = being_ids(&game)[0];
+    (game, id)
+}
+
+#[test]
+fn time_scale_stretches_each_step() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, id) = new_mover(GameConfig::new().with_time_scale(0.5), &mut window);
+    game.step(1.0, &mut window, false);
+    assert!(close(get_pos(&game, id)[0], 0.5));
+    game.set_time_scale(2.0);
+    assert_eq!(game.get_time_scale(), 2.0);
+    game.step(1.0, &mut window, false);
+    assert!(close(get_pos(&game, id)[0], 2.5));
+}
+
+#[test]
+fn zero_time_scale_freezes_movement() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, id) = new_mover(GameConfig::new().with_time_scale(0.0), &mut window);
+    game.step(1.0, &mut window, false);
+    assert_eq!(get_pos(&game, id)[0], 0.0);
+}
+
+#[test]
+fn requested_step_runs_one_tick_while_paused() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, id) = new_mover(GameConfig::new(), &mut window);
+    game.set_paused(true).unwrap();
+    game.step(1.0, &mut window, false);
+    assert_eq!(get_pos(&game, id)[0], 0.0);
+    game.request_step();
+    game.step(1.0, &mut window, false);
+    assert!(close(get_pos(&game, id)[0], 1.0));
+    assert!(game.is_paused().unwrap());
+    game.step(1.0, &mut window, false);
+    assert!(close(get_pos(&game, id)[0], 1.0));
+}
+
+#[test]
+fn requested_step_does_nothing_extra_when_running() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, id) = new_mover(GameConfig::new(), &mut window);
+    game.request_step();
+    game.step(1.0, &mut window, false);
+    assert!(!game.is_paused().unwrap());
+    assert!(close(get_pos(&game, id)[0], 1.0));
+}
@@ -0,0 +1,56 @@
+#[macro_use]
+extern crate polyclops;
+
+mod common;
+
+use polyclops::{Game, GameConfig, Headless, BeingArgs, TracePhase, Vec2};
+
+use common::{QuadType, COUNTS_TICKS, new_game, new_base, new_being, with_flag, being_ids};
+
+fn traced_game(config: GameConfig, window: &mut Headless) -> Game<QuadType> {
+    let mut game = new_game(config);
+    let counter = with_flag(BeingArgs::new(), COUNTS_TICKS);
+    game.start(vec!(new_base(), new_being(counter.clone()), new_being(counter.clone()), new_being(counter)), window);
+    game.start_tracing();
+    game.tick_once(window);
+    game.tick_once(window);
+    game
+}
+
+// each quad's count event is traced once per tick, stamped with the tick and world it ran in
+fn check_counts(config: GameConfig) {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let mut game = traced_game(config, &mut window);
+    let ids = being_ids(&game);
+    let world_id = game.get_active_world_id();
+    let tracer = game.stop_tracing().unwrap();
+    let entries = tracer.get_entries();
+    assert!(entries.iter().all(|entry| entry.world_id == world_id));
+    let first = entries[0].tick;
+    for tick in first..first + 2 {
+        for id in ids.iter() {
+            let count = entries.iter().filter(|entry| entry.tick == tick && entry.phase == TracePhase::Tick && entry.name == "Sca3" && entry.target == Some(*id)).count();
+            assert_eq!(count, 1);
+        }
+    }
+}
+
+#[test]
+fn deterministic_ticks_trace_every_event() {
+    check_counts(GameConfig::new().with_deterministic(true));
+}
+
+#[test]
+fn parallel_ticks_trace_every_event() {
+    check_counts(GameConfig::new());
+}
+
+#[test]
+fn stopping_takes_the_entries() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let mut game = traced_game(GameConfig::new().with_deterministic(true), &mut window);
+    assert!(!game.stop_tracing().unwrap().get_entries().is_empty());
+    assert!(game.get_tracer().is_none());
+    game.tick_once(&mut window);
+    assert!(game.stop_tracing().is_none());
+}
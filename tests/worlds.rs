@@ -0,0 +1,104 @@
+#[macro_use]
+extern crate polyclops;
+
+mod common;
+
+use std::sync::{Arc};
+
+use polyclops::{Game, GameConfig, Headless, HeadlessCall, BeingArgs, ID, IDType, World, WorldEvent, TickEvent, PolyclopsError, Vec2, init};
+
+use common::{QuadType, COUNTS_TICKS, new_game, new_base, new_being, with_flag};
+
+fn counter_events() -> Vec<WorldEvent<QuadType>> {
+    vec!(new_base(), new_being(with_flag(BeingArgs::new(), COUNTS_TICKS)))
+}
+
+// a counter in the active world and another in a second world
+fn two_worlds(window: &mut Headless) -> (Game<QuadType>, ID, ID) {
+    let mut game = new_game(GameConfig::new().with_deterministic(true));
+    let first = game.get_active_world_id();
+    game.start(counter_events(), window);
+    let second = game.add_world(World::new(Vec2::from([64.0, 64.0])));
+    game.start_world(second, counter_events(), window);
+    (game, first, second)
+}
+
+fn ticks_counted(game: &Game<QuadType>, world_id: ID) -> f32 {
+    let world = game.get_world(world_id).unwrap().read().unwrap();
+    assert_eq!(world.get_beings().len(), 1);
+    let count = world.get_beings().values().next().unwrap().read().unwrap().get_sca3()[0] - 1.0;
+    count
+}
+
+#[test]
+fn only_the_active_world_ticks() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, first, second) = two_worlds(&mut window);
+    game.tick_once(&mut window);
+    assert_eq!(ticks_counted(&game, first), 1.0);
+    assert_eq!(ticks_counted(&game, second), 0.0);
+    game.set_active_world(second).unwrap();
+    game.tick_once(&mut window);
+    assert_eq!(ticks_counted(&game, first), 1.0);
+    assert_eq!(ticks_counted(&game, second), 1.0);
+}
+
+#[test]
+fn background_worlds_tick_when_asked() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, first, second) = two_worlds(&mut window);
+    game.get_world(second).unwrap().write().unwrap().set_tick_in_background(true);
+    game.tick_once(&mut window);
+    assert_eq!(ticks_counted(&game, first), 1.0);
+    assert_eq!(ticks_counted(&game, second), 1.0);
+}
+
+#[test]
+fn pausing_is_per_world() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, first, second) = two_worlds(&mut window);
+    game.set_paused(true).unwrap();
+    game.set_active_world(second).unwrap();
+    assert!(!game.is_paused().unwrap());
+    assert!(game.get_world(first).unwrap().read().unwrap().is_paused());
+}
+
+#[test]
+fn only_the_active_world_is_drawn() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, _, second) = two_worlds(&mut window);
+    game.set_active_world(second).unwrap();
+    window.clear_calls();
+    game.render(&mut window, 1.0);
+    let draws = window.get_calls().iter().filter(|call| match **call {
+        HeadlessCall::DrawEntity(_, _, _, _) => true,
+        _ => false,
+    }).count();
+    assert_eq!(draws, 1);
+}
+
+#[test]
+fn set_active_world_event_switches_after_the_step() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, first, second) = two_worlds(&mut window);
+    game.start(vec!(WorldEvent::Tick(TickEvent::SetActiveWorld(second))), &mut window);
+    assert_eq!(game.get_active_world_id(), first);
+    game.tick_once(&mut window);
+    assert_eq!(game.get_active_world_id(), second);
+    assert_eq!(ticks_counted(&game, first), 1.0);
+    assert_eq!(ticks_counted(&game, second), 0.0);
+}
+
+#[test]
+fn switching_to_a_missing_world_is_an_error() {
+    let mut window = Headless::new(Vec2::from([64.0, 64.0]));
+    let (mut game, first, _) = two_worlds(&mut window);
+    // a fresh manager's first ids are the ones the game already gave its worlds
+    let manager = Arc::new(init());
+    let missing = (0..10).map(|_| ID::new(manager.clone(), IDType::World)).last().unwrap();
+    match game.set_active_world(missing) {
+        Err(PolyclopsError::MissingWorld(id)) => assert_eq!(id, missing),
+        _ => panic!("expected a missing world error"),
+    }
+    assert_eq!(game.get_active_world_id(), first);
+}